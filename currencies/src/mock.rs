@@ -72,6 +72,7 @@ parameter_type_with_key! {
 
 parameter_types! {
 	pub DustAccount: AccountId = PalletId(*b"orml/dst").into_account();
+	pub const StringLimit: u32 = 8;
 	pub MaxLocks: u32 = 100_000;
 }
 
@@ -84,6 +85,10 @@ impl orml_tokens::Config for Runtime {
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = orml_tokens::TransferDust<Runtime, DustAccount>;
 	type MaxLocks = MaxLocks;
+	type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+	type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+	type RegisterOrigin = frame_system::EnsureRoot<AccountId>;
+	type StringLimit = StringLimit;
 }
 
 pub const NATIVE_CURRENCY_ID: CurrencyId = 1;
@@ -172,6 +177,7 @@ impl ExtBuilder {
 				.into_iter()
 				.filter(|(_, currency_id, _)| *currency_id != NATIVE_CURRENCY_ID)
 				.collect::<Vec<_>>(),
+			metadata: vec![],
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
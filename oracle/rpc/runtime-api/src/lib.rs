@@ -2,16 +2,27 @@
 
 use codec::Codec;
 use sp_std::vec::Vec;
+pub use primitives::{DataProviderId, FeederInfo, OracleKeyInfo};
 
 sp_api::decl_runtime_apis! {
-	pub trait OracleApi<AccountId, Key, Value> where
+	/// Version 2 adds `get_many`, `get_by_provider`, `get_history`,
+	/// `get_feeders` and `get_key_info`.
+	#[api_version(2)]
+	pub trait OracleApi<AccountId, Balance, Key, Value, Moment> where
 		AccountId: Codec,
+		Balance: Codec,
 		Key: Codec,
 		Value: Codec,
+		Moment: Codec,
 	{
 		fn get(key: Key) -> Option<Value>;
+		fn get_many(keys: Vec<Key>) -> Vec<Option<Value>>;
 		fn get_polkafoundry(key: Key) -> Option<Value>;
 		fn get_concrete(key: Key, feeder: AccountId) -> Option<Value>;
 		fn get_all_values() -> Vec<(Key, Option<Value>)>;
+		fn get_by_provider(provider_id: DataProviderId, key: Key, feeder: Option<AccountId>) -> Vec<(Option<AccountId>, Value)>;
+		fn get_history(key: Key, from: Moment, to: Moment) -> Vec<Value>;
+		fn get_feeders() -> Vec<FeederInfo<AccountId, Balance>>;
+		fn get_key_info(key: Key) -> OracleKeyInfo<Moment>;
 	}
 }
@@ -3,26 +3,58 @@ use std::sync::Arc;
 use codec::Codec;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiError, ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
 	generic::BlockId,
-	traits::{Block as BlockT},
+	traits::{Block as BlockT, NumberFor, One, Saturating, UniqueSaturatedInto},
 };
-pub use pkfp_oracle_runtime_api::OracleApi as OracleRuntimeApi;
+pub use pkfp_oracle_runtime_api::{DataProviderId, FeederInfo, OracleApi as OracleRuntimeApi, OracleKeyInfo};
+pub use subscription::{OracleSubscription, OracleSubscriptionApi, ValuesChange};
+
+mod subscription;
+
+/// Maximum number of blocks queried by `oracle_getHistory`.
+pub const MAX_HISTORY_BLOCKS: u32 = 1_000;
 
 pub enum Error {
+	/// The runtime call failed, e.g. panicked.
 	RuntimeError,
+	/// The block hash is not known.
+	UnknownBlock,
+	/// The runtime does not implement the required `OracleApi` version.
+	ApiVersionMismatch,
+	/// Parameters or return value could not be decoded.
+	DecodeError,
 }
 
 impl From<Error> for i64 {
 	fn from(e: Error) -> i64 {
 		match e {
 			Error::RuntimeError => 1,
+			Error::UnknownBlock => 2,
+			Error::ApiVersionMismatch => 3,
+			Error::DecodeError => 4,
 		}
 	}
 }
 
+/// Map a runtime API error to an RPC error, separating decoding failures from
+/// runtime failures.
+pub(crate) fn api_error(message: &str, e: ApiError) -> RpcError {
+	let code = match e {
+		ApiError::FailedToDecodeReturnValue { .. }
+		| ApiError::FailedToConvertReturnValue { .. }
+		| ApiError::FailedToConvertParameter { .. } => Error::DecodeError,
+		_ => Error::RuntimeError,
+	};
+	RpcError {
+		code: ErrorCode::ServerError(code.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
 /// An implementation of contract specific RPC methods.
 pub struct Oracle<C, B> {
 	client: Arc<C>,
@@ -39,8 +71,61 @@ impl<C, B> Oracle<C, B> {
 	}
 }
 
+impl<C, Block> Oracle<C, Block> where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+{
+	/// Block `at`, the best block if not supplied, if it is known and its
+	/// runtime implements `OracleApi` of at least `version`.
+	fn block_id<AccountId, Balance, Key, Value, Moment>(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+		version: u32,
+	) -> Result<BlockId<Block>> where
+		C::Api: OracleRuntimeApi<Block, AccountId, Balance, Key, Value, Moment>,
+		AccountId: Codec,
+		Balance: Codec,
+		Key: Codec,
+		Value: Codec,
+		Moment: Codec,
+	{
+		let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let at = BlockId::hash(hash);
+
+		let header = self.client.header(at).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::UnknownBlock.into()),
+			message: "Unable to get block.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		if header.is_none() {
+			return Err(RpcError {
+				code: ErrorCode::ServerError(Error::UnknownBlock.into()),
+				message: format!("Unknown block {:?}.", hash),
+				data: None,
+			});
+		}
+
+		let api_version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn OracleRuntimeApi<Block, AccountId, Balance, Key, Value, Moment>>(&at)
+			.map_err(|e| api_error("Unable to get runtime API version.", e))?;
+		match api_version {
+			Some(api_version) if api_version >= version => Ok(at),
+			_ => Err(RpcError {
+				code: ErrorCode::ServerError(Error::ApiVersionMismatch.into()),
+				message: format!(
+					"Runtime implements OracleApi version {:?}, version {} is required.",
+					api_version, version
+				),
+				data: None,
+			}),
+		}
+	}
+}
+
 #[rpc]
-pub trait OracleApi<BlockHash, AccountId, Key, Value> {
+pub trait OracleApi<BlockHash, BlockNumber, AccountId, Balance, Key, Value, Moment> {
 	#[rpc(name = "oracle_get")]
 	fn get(
 		&self,
@@ -48,6 +133,24 @@ pub trait OracleApi<BlockHash, AccountId, Key, Value> {
 		at: Option<BlockHash>,
 	) -> Result<Option<Value>>;
 
+	/// Combined values of `keys` at one block.
+	#[rpc(name = "oracle_getMany")]
+	fn get_many(
+		&self,
+		keys: Vec<Key>,
+		at: Option<BlockHash>,
+	) -> Result<Vec<Option<Value>>>;
+
+	/// Combined value of `key` at each block of `[from_block, to_block]`,
+	/// at most `MAX_HISTORY_BLOCKS` blocks.
+	#[rpc(name = "oracle_getHistory")]
+	fn get_block_history(
+		&self,
+		key: Key,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+	) -> Result<Vec<(BlockNumber, Option<Value>)>>;
+
 	#[rpc(name = "oracle_get_polkafoundry")]
 	fn get_polkafoundry(
 		&self,
@@ -68,31 +171,109 @@ pub trait OracleApi<BlockHash, AccountId, Key, Value> {
 		&self,
 		at: Option<BlockHash>,
 	) -> Result<Vec<(Key, Option<Value>)>> ;
+
+	#[rpc(name = "oracle_get_by_provider")]
+	fn get_by_provider(
+		&self,
+		provider_id: DataProviderId,
+		key: Key,
+		feeder: Option<AccountId>,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(Option<AccountId>, Value)>>;
+
+	/// Historical combined values of `key` timestamped within `[from, to]`.
+	#[rpc(name = "oracle_getHistoryByTime")]
+	fn get_history(
+		&self,
+		key: Key,
+		from: Moment,
+		to: Moment,
+		at: Option<BlockHash>,
+	) -> Result<Vec<Value>>;
+
+	/// Feeders with their fees and whether they fed at the block.
+	#[rpc(name = "oracle_get_feeders")]
+	fn get_feeders(
+		&self,
+		at: Option<BlockHash>,
+	) -> Result<Vec<FeederInfo<AccountId, Balance>>>;
+
+	/// Freshness of the combined value of `key`.
+	#[rpc(name = "oracle_get_key_info")]
+	fn get_key_info(
+		&self,
+		key: Key,
+		at: Option<BlockHash>,
+	) -> Result<OracleKeyInfo<Moment>>;
 }
 
-impl<C, Block, AccountId, Key, Value> OracleApi<<Block as BlockT>::Hash, AccountId, Key, Value> for Oracle<C, Block> where
+impl<C, Block, AccountId, Balance, Key, Value, Moment>
+	OracleApi<<Block as BlockT>::Hash, NumberFor<Block>, AccountId, Balance, Key, Value, Moment> for Oracle<C, Block> where
 	Block: BlockT,
 	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-	C::Api: OracleRuntimeApi<Block, AccountId, Key, Value>,
+	C::Api: OracleRuntimeApi<Block, AccountId, Balance, Key, Value, Moment>,
 	AccountId: Codec,
-	Key: Codec,
+	Balance: Codec,
+	Key: Codec + Clone,
 	Value: Codec,
+	Moment: Codec,
 {
 	fn get(
 		&self,
 		key: Key,
 		at: Option<<Block as BlockT>::Hash>
 	) -> Result<Option<Value>> {
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 1)?;
+		self.client
+			.runtime_api()
+			.get(&at, key)
+			.map_err(|e| api_error("Unable to get value.", e))
+	}
+
+	fn get_many(
+		&self,
+		keys: Vec<Key>,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<Vec<Option<Value>>> {
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 2)?;
+		self.client
+			.runtime_api()
+			.get_many(&at, keys)
+			.map_err(|e| api_error("Unable to get values.", e))
+	}
+
+	fn get_block_history(
+		&self,
+		key: Key,
+		from_block: NumberFor<Block>,
+		to_block: NumberFor<Block>,
+	) -> Result<Vec<(NumberFor<Block>, Option<Value>)>> {
+		self.block_id::<AccountId, Balance, Key, Value, Moment>(None, 1)?;
+		// Blocks after the best block are not known yet.
+		let to_block = to_block.min(self.client.info().best_number);
+		if from_block > to_block {
+			return Ok(vec![]);
+		}
+		let len: u32 = to_block.saturating_sub(from_block).unique_saturated_into();
+		if len >= MAX_HISTORY_BLOCKS {
+			return Err(RpcError {
+				code: ErrorCode::InvalidParams,
+				message: format!("Cannot query more than {} blocks.", MAX_HISTORY_BLOCKS),
+				data: None,
+			});
+		}
+
 		let api = self.client.runtime_api();
-		let at = BlockId::hash(at.unwrap_or(
-			// If the block hash is not supplied assume the best block.
-			self.client.info().best_hash,
-		));
-		api.get(&at, key).map_err(|e| RpcError {
-			code: ErrorCode::ServerError(Error::RuntimeError.into()),
-			message: "Unable to get value.".into(),
-			data: Some(format!("{:?}", e).into()),
-		})
+		let mut values = Vec::with_capacity(len as usize + 1);
+		let mut number = from_block;
+		for _ in 0..=len {
+			let value = api
+				.get(&BlockId::number(number), key.clone())
+				.map_err(|e| api_error(&format!("Unable to get value at block {:?}.", number), e))?;
+			values.push((number, value));
+			number = number.saturating_add(One::one());
+		}
+		Ok(values)
 	}
 
 	fn get_polkafoundry(
@@ -100,16 +281,11 @@ impl<C, Block, AccountId, Key, Value> OracleApi<<Block as BlockT>::Hash, Account
 		key: Key,
 		at: Option<<Block as BlockT>::Hash>
 	) -> Result<Option<Value>> {
-		let api = self.client.runtime_api();
-		let at = BlockId::hash(at.unwrap_or(
-			// If the block hash is not supplied assume the best block.
-			self.client.info().best_hash,
-		));
-		api.get_polkafoundry(&at, key).map_err(|e| RpcError {
-			code: ErrorCode::ServerError(Error::RuntimeError.into()),
-			message: "Unable to get PolkaFoundry value.".into(),
-			data: Some(format!("{:?}", e).into()),
-		})
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 1)?;
+		self.client
+			.runtime_api()
+			.get_polkafoundry(&at, key)
+			.map_err(|e| api_error("Unable to get PolkaFoundry value.", e))
 	}
 
 	fn get_concrete(
@@ -118,31 +294,72 @@ impl<C, Block, AccountId, Key, Value> OracleApi<<Block as BlockT>::Hash, Account
 		feeder: AccountId,
 		at: Option<<Block as BlockT>::Hash>
 	) -> Result<Option<Value>> {
-		let api = self.client.runtime_api();
-		let at = BlockId::hash(at.unwrap_or(
-			// If the block hash is not supplied assume the best block.
-			self.client.info().best_hash,
-		));
-		api.get_concrete(&at, key, feeder).map_err(|e| RpcError {
-			code: ErrorCode::ServerError(Error::RuntimeError.into()),
-			message: "Unable to get concrete value.".into(),
-			data: Some(format!("{:?}", e).into()),
-		})
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 1)?;
+		self.client
+			.runtime_api()
+			.get_concrete(&at, key, feeder)
+			.map_err(|e| api_error("Unable to get concrete value.", e))
 	}
 
 	fn get_all_values(
 		&self,
 		at: Option<<Block as BlockT>::Hash>
 	) -> Result<Vec<(Key, Option<Value>)>>  {
-		let api = self.client.runtime_api();
-		let at = BlockId::hash(at.unwrap_or(
-			// If the block hash is not supplied assume the best block.
-			self.client.info().best_hash,
-		));
-		api.get_all_values(&at).map_err(|e| RpcError {
-			code: ErrorCode::ServerError(Error::RuntimeError.into()),
-			message: "Unable to get all value.".into(),
-			data: Some(format!("{:?}", e).into()),
-		})
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 1)?;
+		self.client
+			.runtime_api()
+			.get_all_values(&at)
+			.map_err(|e| api_error("Unable to get all value.", e))
+	}
+
+	fn get_by_provider(
+		&self,
+		provider_id: DataProviderId,
+		key: Key,
+		feeder: Option<AccountId>,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<Vec<(Option<AccountId>, Value)>> {
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 2)?;
+		self.client
+			.runtime_api()
+			.get_by_provider(&at, provider_id, key, feeder)
+			.map_err(|e| api_error("Unable to get value by provider.", e))
+	}
+
+	fn get_history(
+		&self,
+		key: Key,
+		from: Moment,
+		to: Moment,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<Vec<Value>> {
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 2)?;
+		self.client
+			.runtime_api()
+			.get_history(&at, key, from, to)
+			.map_err(|e| api_error("Unable to get history.", e))
+	}
+
+	fn get_feeders(
+		&self,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<Vec<FeederInfo<AccountId, Balance>>> {
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 2)?;
+		self.client
+			.runtime_api()
+			.get_feeders(&at)
+			.map_err(|e| api_error("Unable to get feeders.", e))
+	}
+
+	fn get_key_info(
+		&self,
+		key: Key,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<OracleKeyInfo<Moment>> {
+		let at = self.block_id::<AccountId, Balance, Key, Value, Moment>(at, 2)?;
+		self.client
+			.runtime_api()
+			.get_key_info(&at, key)
+			.map_err(|e| api_error("Unable to get key info.", e))
 	}
 }
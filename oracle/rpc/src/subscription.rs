@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use codec::Codec;
+use futures::{future, StreamExt, TryStreamExt};
+use jsonrpc_core::{
+	futures::{stream as stream01, Future as Future01, Sink as Sink01, Stream as Stream01},
+	Result,
+};
+use jsonrpc_derive::rpc;
+use jsonrpc_pubsub::{manager::SubscriptionManager, typed::Subscriber, SubscriptionId};
+use sc_client_api::BlockchainEvents;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use crate::OracleRuntimeApi;
+
+/// Combined values of watched keys that changed at a block.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValuesChange<BlockHash, Key, Value> {
+	/// Best block the values are read at
+	pub block: BlockHash,
+	/// Changed keys with their new combined value
+	pub changes: Vec<(Key, Option<Value>)>,
+}
+
+#[rpc]
+pub trait OracleSubscriptionApi<BlockHash, AccountId, Balance, Key, Value, Moment> {
+	/// RPC metadata
+	type Metadata;
+
+	/// Subscribe to the combined values of `keys`. The current values are
+	/// sent first, then the changed values at every new best block.
+	#[pubsub(subscription = "oracle_values", subscribe, name = "oracle_subscribeValues")]
+	fn subscribe_values(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<ValuesChange<BlockHash, Key, Value>>,
+		keys: Vec<Key>,
+	);
+
+	/// Unsubscribe from the combined values.
+	#[pubsub(subscription = "oracle_values", unsubscribe, name = "oracle_unsubscribeValues")]
+	fn unsubscribe_values(
+		&self,
+		metadata: Option<Self::Metadata>,
+		id: SubscriptionId,
+	) -> Result<bool>;
+}
+
+/// Subscriptions to combined values of the oracle.
+pub struct OracleSubscription<C, B> {
+	client: Arc<C>,
+	subscriptions: SubscriptionManager,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> OracleSubscription<C, B> {
+	/// Create new `OracleSubscription` with the given reference to the client.
+	pub fn new(client: Arc<C>, subscriptions: SubscriptionManager) -> Self {
+		OracleSubscription {
+			client,
+			subscriptions,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Combined values of `keys` at block `hash`.
+fn values_at<C, Block, AccountId, Balance, Key, Value, Moment>(
+	client: &C,
+	hash: Block::Hash,
+	keys: &[Key],
+) -> std::result::Result<Vec<Option<Value>>, sp_api::ApiError>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: OracleRuntimeApi<Block, AccountId, Balance, Key, Value, Moment>,
+	AccountId: Codec,
+	Balance: Codec,
+	Key: Codec + Clone,
+	Value: Codec,
+	Moment: Codec,
+{
+	let api = client.runtime_api();
+	let at = BlockId::hash(hash);
+	keys.iter().map(|key| api.get(&at, key.clone())).collect()
+}
+
+impl<C, Block, AccountId, Balance, Key, Value, Moment>
+	OracleSubscriptionApi<<Block as BlockT>::Hash, AccountId, Balance, Key, Value, Moment> for OracleSubscription<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C::Api: OracleRuntimeApi<Block, AccountId, Balance, Key, Value, Moment>,
+	AccountId: Codec + Send + Sync + 'static,
+	Balance: Codec + Send + Sync + 'static,
+	Key: Codec + Clone + Serialize + Send + Sync + 'static,
+	Value: Codec + Clone + PartialEq + Serialize + Send + Sync + 'static,
+	Moment: Codec + Send + Sync + 'static,
+{
+	type Metadata = sc_rpc_api::Metadata;
+
+	fn subscribe_values(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<ValuesChange<<Block as BlockT>::Hash, Key, Value>>,
+		keys: Vec<Key>,
+	) {
+		let client = self.client.clone();
+		let best_hash = client.info().best_hash;
+		let initial = match values_at::<_, Block, AccountId, Balance, _, _, Moment>(&*client, best_hash, &keys) {
+			Ok(values) => values,
+			Err(e) => {
+				let _ = subscriber.reject(crate::api_error("Unable to get values.", e));
+				return;
+			}
+		};
+		let initial_change = ValuesChange {
+			block: best_hash,
+			changes: keys.iter().cloned().zip(initial.iter().cloned()).collect(),
+		};
+
+		let mut last = initial;
+		let stream = self
+			.client
+			.import_notification_stream()
+			.filter(|notification| future::ready(notification.is_new_best))
+			.filter_map(move |notification| {
+				let values = match values_at::<_, Block, AccountId, Balance, _, _, Moment>(&*client, notification.hash, &keys) {
+					Ok(values) => values,
+					Err(e) => {
+						log::warn!("Unable to get oracle values at {:?}: {:?}", notification.hash, e);
+						return future::ready(None);
+					}
+				};
+				let changes: Vec<_> = keys
+					.iter()
+					.zip(values.iter())
+					.zip(last.iter())
+					.filter(|((_, value), prev)| value != prev)
+					.map(|((key, value), _)| (key.clone(), value.clone()))
+					.collect();
+				last = values;
+
+				if changes.is_empty() {
+					return future::ready(None);
+				}
+				future::ready(Some(ValuesChange {
+					block: notification.hash,
+					changes,
+				}))
+			})
+			.map(|change| Ok::<_, ()>(Ok(change)))
+			.compat();
+
+		self.subscriptions.add(subscriber, |sink| {
+			sink.sink_map_err(|e| log::warn!("Error sending oracle notifications: {:?}", e))
+				.send_all(stream01::iter_result(vec![Ok(Ok(initial_change))]).chain(stream))
+				// the subscription is over when the stream ends
+				.map(|_| ())
+		});
+	}
+
+	fn unsubscribe_values(&self, _metadata: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool> {
+		Ok(self.subscriptions.cancel(id))
+	}
+}
@@ -10,32 +10,144 @@ pub use frame_benchmarking::{
 };
 const USER_SEED: u32 = 999666;
 
+fn setup_feeders<T: Config>(f: u32) -> Vec<T::AccountId> {
+	let mut feeders: Vec<T::AccountId> = (0..f).map(|i| account("feeder", i, USER_SEED)).collect();
+	feeders.sort();
+	<Feeders<T>>::put(BoundedVec::try_from(feeders.clone()).unwrap());
+	feeders
+}
+
+fn feed_keys<T: Config>(feeder: &T::AccountId, k: u32, value: u32) -> Vec<(T::FeedKey, T::FeedValue)>
+	where T::FeedKey: From<u32>
+{
+	let values: Vec<(T::FeedKey, T::FeedValue)> = (0..k)
+		.map(|i| (i.into(), T::FeedValue::from_linear(value.into())))
+		.collect();
+	assert_ok!(Oracle::<T>::do_feed_values(feeder.clone(), values.clone()));
+	values
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, USER_SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
 benchmarks! {
+	where_clause { where T::FeedKey: From<u32> }
+
 	elect_feeder {
-		let feeder: T::AccountId = account("feeder", 0u32, USER_SEED);
+		let f in 1 .. T::MaxFeeders::get() - 1;
+		setup_feeders::<T>(f);
+		let feeder: T::AccountId = account("candidate", 0u32, USER_SEED);
 	}: _(RawOrigin::Root, feeder.clone())
 	verify {
-		let mut feeders = <Feeders<T>>::get();
+		let feeders = <Feeders<T>>::get();
 		assert_ok!(feeders.binary_search(&feeder));
 	}
+
+	remove_feeder {
+		let f in 1 .. T::MaxFeeders::get();
+		let k in 1 .. T::MaxFeedValues::get();
+		let feeders = setup_feeders::<T>(f);
+		let feeder = feeders[0].clone();
+		feed_keys::<T>(&feeder, k, 100);
+	}: _(RawOrigin::Root, feeder.clone(), k)
+	verify {
+		let feeders = <Feeders<T>>::get();
+		assert!(feeders.binary_search(&feeder).is_err());
+	}
+
+	feed_values {
+		let k in 1 .. T::MaxFeedValues::get();
+		let f in 1 .. T::MaxFeeders::get();
+		let feeders = setup_feeders::<T>(f);
+		let caller = feeders[f as usize - 1].clone();
+		let values: Vec<(T::FeedKey, T::FeedValue)> = (0..k)
+			.map(|i| (i.into(), T::FeedValue::from_linear(100u32.into())))
+			.collect();
+	}: _(RawOrigin::Signed(caller.clone()), values)
+	verify {
+		assert_eq!(<FeedCount<T>>::get(&T::FeedKey::from(0u32), &caller), 1);
+	}
+
+	combine {
+		let f in 1 .. T::MaxFeeders::get();
+		let feeders = setup_feeders::<T>(f);
+		for (i, feeder) in feeders.iter().enumerate() {
+			// every bonded feeder deviates from the previous one to hit the slashing path
+			<Bonds<T>>::insert(feeder, BalanceOf::<T>::from(100u32));
+			feed_keys::<T>(feeder, 1, 100 * (i as u32 + 1));
+		}
+	}: {
+		Oracle::<T>::on_finalize(1u32.into());
+	}
+	verify {
+		assert_eq!(<UpdatedKeys<T>>::iter().count(), 0);
+	}
+
 	set_fee {
-		let caller: T::AccountId = whitelisted_caller();
+		let f in 1 .. T::MaxFeeders::get();
+		let feeders = setup_feeders::<T>(f);
+		let caller = feeders[0].clone();
 	}: _(RawOrigin::Signed(caller.clone()), 1u32.into())
 	verify {
-		assert_eq!(<Fees<T>>::get(caller), 1u32.into());
+		assert_eq!(<Fees<T>>::get(caller), Some(1u32.into()));
 	}
-	remove_feeder {
-		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Root, caller.clone())
+
+	read_value {
+		let f in 1 .. T::MaxFeeders::get();
+		let feeders = setup_feeders::<T>(f);
+		for feeder in feeders.iter() {
+			feed_keys::<T>(feeder, 1, 100);
+		}
+		let key: T::FeedKey = 0u32.into();
+		<Values<T>>::insert(&key, TimestampedValue {
+			value: T::FeedValue::from_linear(100u32.into()),
+			timestamp: T::Time::now(),
+		});
+		<Contributors<T>>::insert(&key, BoundedVec::try_from(feeders).unwrap_or_default());
+		let caller = funded_account::<T>("reader", 0);
+	}: _(RawOrigin::Signed(caller), key)
+
+	force_combine {
+		let f in 1 .. T::MaxFeeders::get();
+		let feeders = setup_feeders::<T>(f);
+		for feeder in feeders.iter() {
+			feed_keys::<T>(feeder, 1, 100);
+		}
+		let key: T::FeedKey = 0u32.into();
+		<Values<T>>::insert(&key, TimestampedValue {
+			value: T::FeedValue::from_linear(1u32.into()),
+			timestamp: T::Time::now(),
+		});
+	}: _(RawOrigin::Root, key)
+
+	bond_feeder {
+		let f in 1 .. T::MaxFeeders::get() - 1;
+		setup_feeders::<T>(f);
+		let caller = funded_account::<T>("candidate", 0);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(<Bonds<T>>::contains_key(&caller));
+	}
+
+	unbond_feeder {
+		let f in 1 .. T::MaxFeeders::get() - 1;
+		let k in 1 .. T::MaxFeedValues::get();
+		setup_feeders::<T>(f);
+		let caller = funded_account::<T>("candidate", 0);
+		assert_ok!(Oracle::<T>::bond_feeder(RawOrigin::Signed(caller.clone()).into()));
+		feed_keys::<T>(&caller, k, 100);
+	}: _(RawOrigin::Signed(caller.clone()), k)
 	verify {
-		let mut feeders = <Feeders<T>>::get();
-		assert_eq!(feeders.binary_search(&caller), Err(0));
+		assert!(!<Bonds<T>>::contains_key(&caller));
 	}
 }
 
 impl_benchmark_test_suite!(
 	Oracle,
-	crate::mock::ExtBuilder::default().feeders(vec![frame_benchmarking::whitelisted_caller()]),
+	crate::mock::ExtBuilder::default(),
 	crate::mock::Test,
 	exec_name = build_and_execute
 );
@@ -7,17 +7,33 @@ use codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Get, Time, Currency},
+	traits::{
+		ChangeMembers, Currency, ExistenceRequirement, Get, InitializeMembers, OnUnbalanced, PalletInfo,
+		ReservableCurrency, SortedMembers, Time,
+	},
 	weights::{Pays, Weight},
+	transactional,
+	BoundedVec,
 	Parameter
 };
 use frame_system::{pallet_prelude::*};
+use sp_runtime::{
+	traits::{Bounded, IdentifyAccount, Saturating, UniqueSaturatedInto, Verify, Zero},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
+	},
+	FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill,
+};
+use sp_std::convert::{TryFrom, TryInto};
 use sp_std::marker;
 use sp_std::{prelude::*, vec};
 
-pub use orml_traits::{CombineData, DataFeeder, DataProvider, DataProviderExtended, OnNewData};
+pub use orml_traits::{CombineData, DataFeeder, DataProvider, DataProviderExtended, GetByKey, OnNewData};
+pub use primitives::{DataProviderId, FeederInfo, OracleKeyInfo};
 
 pub use pallet::*;
+pub use offchain::{HttpFeeder, OffchainFeeder, PriceSource};
+pub use weights::WeightInfo;
 
 #[cfg(test)]
 mod mock;
@@ -25,11 +41,22 @@ mod mock;
 mod tests;
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarking;
+pub mod migrations;
+pub mod offchain;
+mod weights;
 
 pub(crate) type MomentOf<T, I = ()> = <<T as Config<I>>::Time as Time>::Moment;
 pub(crate) type TimestampedValueOf<T, I = ()> = TimestampedValue<<T as Config<I>>::FeedValue, MomentOf<T, I>>;
 pub(crate) type BalanceOf<T, I = ()> =
 <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub(crate) type FeedPayloadOf<T, I = ()> = FeedPayload<
+	<T as frame_system::Config>::AccountId,
+	<T as Config<I>>::FeedKey,
+	<T as Config<I>>::FeedValue,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub(crate) type NegativeImbalanceOf<T, I = ()> =
+<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
 #[derive(Encode, Decode, RuntimeDebug, Eq, PartialEq, Clone, Copy, Ord, PartialOrd)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -38,7 +65,91 @@ pub struct TimestampedValue<Value, Moment> {
 	pub timestamp: Moment,
 }
 
-//TODO: Should we deduct fee from callers?
+/// Tag prefixed to the signed bytes of a `FeedPayload`.
+pub const FEED_PAYLOAD_TAG: &[u8] = b"pkfp-oracle-feed";
+
+/// Values signed by a feeder, to be submitted by anyone as an unsigned
+/// transaction. The feeder signs `Pallet::signing_payload`, which binds it
+/// to one oracle instance of one chain.
+#[derive(Encode, Decode, RuntimeDebug, Eq, PartialEq, Clone)]
+pub struct FeedPayload<AccountId, Key, Value, BlockNumber> {
+	pub feeder: AccountId,
+	pub values: Vec<(Key, Value)>,
+	/// Block the payload is signed at, it expires `FeedPayloadLongevity`
+	/// blocks later
+	pub block_number: BlockNumber,
+	/// Must be the current `FeedNonces` of the feeder
+	pub nonce: u32,
+}
+
+/// Handling of a feeder feeding a key more than once in a block.
+#[derive(Encode, Decode, RuntimeDebug, Eq, PartialEq, Clone, Copy)]
+pub enum FeedPolicy {
+	/// Reject values for keys already fed in the block
+	Reject,
+	/// Replace the value fed earlier in the block
+	Overwrite,
+	/// Use the mean of the values fed in the block
+	Merge,
+}
+
+/// Combine the raw values of a key together with the feeders that fed them.
+pub trait CombineFeederData<AccountId, Key, TimestampedValue> {
+	/// Combine the raw values of `key` fed by the given feeders. Returns the
+	/// combined value with the feeders whose values it is combined from, or
+	/// `prev_value` with no feeders if the values cannot be combined.
+	fn combine_feeder_data(
+		key: &Key,
+		values: Vec<(AccountId, TimestampedValue)>,
+		prev_value: Option<TimestampedValue>,
+	) -> Option<(TimestampedValue, Vec<AccountId>)>;
+}
+
+/// Feed value that maps onto `u128` preserving order and ratios, so that
+/// values can be compared relatively.
+pub trait LinearValue: Sized {
+	/// Convert into the linear representation
+	fn into_linear(&self) -> u128;
+	/// Convert from the linear representation, saturating if out of range
+	fn from_linear(value: u128) -> Self;
+
+	/// Relative distance of `self` from `reference`, saturating at 100%.
+	fn deviation(&self, reference: &Self) -> Permill {
+		let value = self.into_linear();
+		let reference = reference.into_linear();
+		let diff = if value > reference { value - reference } else { reference - value };
+		Permill::from_rational_approximation(diff, reference)
+	}
+}
+
+macro_rules! impl_linear_value {
+	($($t:ty),*) => {
+		$(
+			impl LinearValue for $t {
+				fn into_linear(&self) -> u128 {
+					(*self).into()
+				}
+
+				fn from_linear(value: u128) -> Self {
+					value.try_into().unwrap_or_else(|_| Bounded::max_value())
+				}
+			}
+		)*
+	};
+}
+
+impl_linear_value!(u8, u16, u32, u64, u128);
+
+impl LinearValue for FixedU128 {
+	fn into_linear(&self) -> u128 {
+		self.into_inner()
+	}
+
+	fn from_linear(value: u128) -> Self {
+		FixedU128::from_inner(value)
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -51,27 +162,97 @@ pub mod pallet {
 		/// The data key type
 		type FeedKey: Parameter + Member;
 		/// The data value type
-		type FeedValue: Parameter + Member + Ord;
+		type FeedValue: Parameter + Member + Ord + LinearValue;
 		/// Provide the implementation to combine raw values to produce
 		/// aggregated value
-		type CombineData: CombineData<Self::FeedKey, TimestampedValueOf<Self, I>>;
-		/// Interface used for balance transfers.
-		type Currency: Currency<Self::AccountId>;
-		/// Fee pay for feeders
+		type CombineData: CombineFeederData<Self::AccountId, Self::FeedKey, TimestampedValueOf<Self, I>>;
+		/// Interface used for balance transfers and feeder bonds.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Default fee paid to a feeder that has not set its own fee
 		#[pallet::constant]
 		type OracleFee: Get<BalanceOf<Self, I>>;
+		/// Deposit reserved to bond as a feeder, bonding is disabled if zero
+		#[pallet::constant]
+		type FeederDeposit: Get<BalanceOf<Self, I>>;
+		/// Maximum deviation of a bonded feeder's value from the combined
+		/// value before it is slashed
+		#[pallet::constant]
+		type SlashTolerance: Get<Permill>;
+		/// Portion of the bond slashed for each deviating value
+		#[pallet::constant]
+		type SlashFraction: Get<Perbill>;
+		/// Handler for the slashed bonds
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+		/// Number of combined values kept in the history of each key
+		#[pallet::constant]
+		type HistoryDepth: Get<u32>;
+		/// Maximum deviation of a newly combined value from the previous one
+		/// before it is rejected, `Permill::one()` disables the check
+		#[pallet::constant]
+		type MaxDeviation: Get<Permill>;
+		/// Maximum number of feeders
+		#[pallet::constant]
+		type MaxFeeders: Get<u32>;
+		/// Maximum number of values fed in one call
+		#[pallet::constant]
+		type MaxFeedValues: Get<u32>;
+		/// First-party feeders whose values make up the `PolkaFoundry`
+		/// provider
+		type PolkaFoundryFeeders: SortedMembers<Self::AccountId>;
+		/// Age after which the raw values of `PolkaFoundryFeeders` no longer
+		/// make up the `PolkaFoundry` provider
+		#[pallet::constant]
+		type PolkaFoundryExpiresIn: Get<MomentOf<Self, I>>;
+		/// Public key of feeders signing feed payloads
+		type FeederPublic: IdentifyAccount<AccountId = Self::AccountId>;
+		/// Signature of feed payloads
+		type FeederSignature: Verify<Signer = Self::FeederPublic> + Parameter + Member;
+		/// Number of blocks a signed feed payload is valid for
+		#[pallet::constant]
+		type FeedPayloadLongevity: Get<Self::BlockNumber>;
+		/// Priority of unsigned transactions feeding signed payloads
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// Handling of a feeder feeding a key more than once in a block
+		type FeedPolicy: Get<FeedPolicy>;
+		/// Offchain worker feeding values, `()` to disable
+		type OffchainFeeder: OffchainFeeder<Self::BlockNumber>;
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::error]
 	pub enum Error<T, I = ()> {
 		/// Sender does not have permission
 		NoPermission,
-		/// Feeder has already feeded at this block
+		/// Feeder has already fed the key at this block
 		AlreadyFeeded,
 		/// Already a feeder
 		AlreadyFeeder,
 		/// Not a feeder
-		NotFeeder
+		NotFeeder,
+		/// No combined value is available for the key
+		NoValue,
+		/// Bonding is disabled
+		BondingDisabled,
+		/// Feeder has no bond
+		NotBonded,
+		/// Feeder has values for more keys than given
+		TooManyKeys,
+		/// Number of feeders exceeds `MaxFeeders`
+		TooManyFeeders,
+		/// Number of fed values exceeds `MaxFeedValues`
+		TooManyFeedValues,
+		/// Combined value is older than the accepted age
+		StaleValue,
+		/// Payload signature is invalid
+		InvalidSignature,
+		/// Payload nonce is not the current nonce of the feeder
+		InvalidNonce,
+		/// Payload has expired
+		PayloadExpired,
+		/// Payload is signed at a future block
+		FuturePayload,
 	}
 
 	#[pallet::event]
@@ -85,52 +266,122 @@ pub mod pallet {
 		FeeSetted(T::AccountId, BalanceOf<T, I>),
 		/// Remove a feeder. [feeder]
 		RemoveFeeder(T::AccountId),
+		/// A reader paid a feeder for a combined value. [reader, feeder, fee]
+		FeePaid(T::AccountId, T::AccountId, BalanceOf<T, I>),
+		/// A combined value is read with fees paid. [reader, key, value]
+		PaidRead(T::AccountId, T::FeedKey, TimestampedValueOf<T, I>),
+		/// New feeder is bonded. [feeder, deposit]
+		FeederBonded(T::AccountId, BalanceOf<T, I>),
+		/// Bonded feeder left and got the bond back. [feeder, amount]
+		FeederUnbonded(T::AccountId, BalanceOf<T, I>),
+		/// Bonded feeder is slashed for a deviating value. [feeder, key,
+		/// amount]
+		FeederSlashed(T::AccountId, T::FeedKey, BalanceOf<T, I>),
+		/// Newly combined value deviates too much and is rejected, the
+		/// previous value is kept. [key, previous, rejected]
+		CombinedValueRejected(T::FeedKey, TimestampedValueOf<T, I>, TimestampedValueOf<T, I>),
+		/// Combined value is forced by root. [key, value]
+		CombinedValueForced(T::FeedKey, TimestampedValueOf<T, I>),
+		/// Incoming member is not elected as feeder because `MaxFeeders` is
+		/// reached. [member]
+		FeederNotElected(T::AccountId),
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn feeders)]
 	pub type Feeders<T: Config<I>, I: 'static = ()> =
-	StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+	StorageValue<_, BoundedVec<T::AccountId, T::MaxFeeders>, ValueQuery>;
 
+	/// Number of values fed for a key by a feeder in the current block,
+	/// cleared with the key in `on_finalize`
 	#[pallet::storage]
-	#[pallet::getter(fn has_feeded)]
-	pub type HasFeeded<T: Config<I>, I: 'static = ()> =
-	StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
+	#[pallet::getter(fn feed_count)]
+	pub type FeedCount<T: Config<I>, I: 'static = ()> =
+	StorageDoubleMap<_, Twox64Concat, T::FeedKey, Twox64Concat, T::AccountId, u32, ValueQuery>;
 
+	/// Keys fed in the current block, combined in `on_finalize`
 	#[pallet::storage]
-	#[pallet::getter(fn is_combined)]
-	pub type IsCombined<T: Config<I>, I: 'static = ()> =
-	StorageMap<_, Twox64Concat, T::FeedKey, bool, ValueQuery>;
+	pub type UpdatedKeys<T: Config<I>, I: 'static = ()> =
+	StorageMap<_, Twox64Concat, T::FeedKey, (), OptionQuery>;
 
+	/// Fee charged by a feeder for each paid read, `OracleFee` if not set
 	#[pallet::storage]
 	#[pallet::getter(fn fees)]
 	pub type Fees<T: Config<I>, I: 'static = ()> =
-	StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T, I>, ValueQuery>;
+	StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T, I>, OptionQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn all_value)]
 	pub type AllValue<T: Config<I>, I: 'static = ()> =
 	StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::FeedKey, TimestampedValueOf<T, I>>;
 
-	/// Combined value, may not be up to date
+	/// Reserved bond of feeders that joined by bonding
+	#[pallet::storage]
+	#[pallet::getter(fn bonds)]
+	pub type Bonds<T: Config<I>, I: 'static = ()> =
+	StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T, I>, OptionQuery>;
+
+	/// Values of bonded feeders not yet checked against a combined value
+	#[pallet::storage]
+	pub type PendingChecks<T: Config<I>, I: 'static = ()> =
+	StorageDoubleMap<_, Twox64Concat, T::FeedKey, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// Feeders whose raw values the last combine of a key used, paid for
+	/// reads of the key
+	#[pallet::storage]
+	#[pallet::getter(fn contributors)]
+	pub type Contributors<T: Config<I>, I: 'static = ()> =
+	StorageMap<_, Twox64Concat, T::FeedKey, BoundedVec<T::AccountId, T::MaxFeeders>, ValueQuery>;
+
+	/// Combined value, updated at the end of every block with new feeds
 	#[pallet::storage]
 	#[pallet::getter(fn values)]
 	pub type Values<T: Config<I>, I: 'static = ()> =
 	StorageMap<_, Twox64Concat, T::FeedKey, TimestampedValueOf<T, I>>;
 
+	/// Ring buffer of past combined values, indexed by `HistoryCount %
+	/// HistoryDepth`
+	#[pallet::storage]
+	pub type History<T: Config<I>, I: 'static = ()> =
+	StorageDoubleMap<_, Twox64Concat, T::FeedKey, Twox64Concat, u32, TimestampedValueOf<T, I>>;
+
+	/// Number of combined values ever written to the history of a key
+	#[pallet::storage]
+	#[pallet::getter(fn history_count)]
+	pub type HistoryCount<T: Config<I>, I: 'static = ()> =
+	StorageMap<_, Twox64Concat, T::FeedKey, u32, ValueQuery>;
+
+	/// Nonce of the next signed feed payload of a feeder
+	#[pallet::storage]
+	#[pallet::getter(fn feed_nonces)]
+	pub type FeedNonces<T: Config<I>, I: 'static = ()> =
+	StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Storage version of the pallet, used by migrations
+	#[pallet::storage]
+	pub(crate) type StorageVersion<T: Config<I>, I: 'static = ()> =
+	StorageValue<_, migrations::Releases, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<T::BlockNumber> for Pallet<T, I> {
-		/// `on_initialize` to return the weight used in `on_finalize`.
-		fn on_initialize(_n: T::BlockNumber) -> Weight {
-			10_000
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate_to_bounded_feeders::<T, I>()
 		}
 
+		/// The weight of `on_finalize` is paid upfront by `feed_values`.
 		fn on_finalize(_n: T::BlockNumber) {
-			// cleanup for next block
-			<HasFeeded<T, I>>::remove_all(None);
+			for (key, _) in <UpdatedKeys<T, I>>::drain() {
+				Self::combine_and_publish(&key);
+				// cleanup for next block, bounded by the number of feeders
+				<FeedCount<T, I>>::remove_prefix(&key, None);
+			}
+		}
+
+		fn offchain_worker(now: T::BlockNumber) {
+			T::OffchainFeeder::feed(now);
 		}
 	}
 
@@ -150,13 +401,18 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
 		fn build(&self) {
-			<Feeders<T, I>>::put(&self.feeders);
+			let mut feeders = self.feeders.clone();
+			feeders.sort();
+			let feeders: BoundedVec<T::AccountId, T::MaxFeeders> =
+				BoundedVec::try_from(feeders).expect("genesis feeders exceed MaxFeeders");
+			<Feeders<T, I>>::put(feeders);
+			<StorageVersion<T, I>>::put(migrations::Releases::V2);
 		}
 	}
 
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::elect_feeder(T::MaxFeeders::get()))]
 		pub fn elect_feeder(
 			origin: OriginFor<T>,
 			feeder: T::AccountId
@@ -164,7 +420,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let mut feeders = <Feeders<T, I>>::get();
 			let location = feeders.binary_search(&feeder).err().ok_or(Error::<T, I>::AlreadyFeeder)?;
-			feeders.insert(location, feeder.clone());
+			feeders.try_insert(location, feeder.clone()).map_err(|_| Error::<T, I>::TooManyFeeders)?;
 
 			<Feeders<T, I>>::put(feeders);
 
@@ -173,44 +429,72 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(0)]
+		/// Remove `feeder` and its values. `keys` must be at least the number
+		/// of keys the feeder has values for.
+		#[pallet::weight(T::WeightInfo::remove_feeder(T::MaxFeeders::get(), *keys))]
+		#[transactional]
 		pub fn remove_feeder(
 			origin: OriginFor<T>,
-			feeder: T::AccountId
+			feeder: T::AccountId,
+			#[pallet::compact] keys: u32,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 			let mut feeders = <Feeders<T, I>>::get();
 			let location = feeders.binary_search(&feeder).ok().ok_or(Error::<T, I>::NotFeeder)?;
+			let feeder_count = feeders.len() as u32;
 			feeders.remove(location);
 
 			<Feeders<T, I>>::put(feeders);
-			<AllValue<T, I>>::remove_prefix(&feeder, None);
+			let removed = Self::remove_values_of(&feeder, keys)?;
+			if let Some(bond) = <Bonds<T, I>>::take(&feeder) {
+				T::Currency::unreserve(&feeder, bond);
+			}
 
 			Self::deposit_event(Event::RemoveFeeder(feeder));
 
-			Ok(().into())
+			Ok(Some(T::WeightInfo::remove_feeder(feeder_count, removed)).into())
 		}
 
-		#[pallet::weight(0)]
+		/// Feed values as a feeder. The weight includes combining the fed
+		/// keys in `on_finalize`.
+		#[pallet::weight(Pallet::<T, I>::feed_values_weight(values.len() as u32, T::MaxFeeders::get()))]
 		pub fn feed_values(
 			origin: OriginFor<T>,
 			values: Vec<(T::FeedKey, T::FeedValue)>
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			ensure!(values.len() <= T::MaxFeedValues::get() as usize, Error::<T, I>::TooManyFeedValues);
 
 			let feeders = <Feeders<T, I>>::get();
 			let _ = feeders.binary_search(&who).ok().ok_or(Error::<T, I>::NoPermission)?;
 
-			if <HasFeeded<T, I>>::get(&who) {
-				Err(Error::<T, I>::AlreadyFeeded)?;
-			}
-
 			Self::do_feed_values(who.clone(), values)?;
 
 			Ok(Pays::No.into())
 		}
 
-		#[pallet::weight(0)]
+		/// Feed values signed by a feeder. Submitted as an unsigned
+		/// transaction by anyone.
+		#[pallet::weight(Pallet::<T, I>::feed_values_weight(payload.values.len() as u32, T::MaxFeeders::get()))]
+		pub fn feed_values_unsigned(
+			origin: OriginFor<T>,
+			payload: FeedPayloadOf<T, I>,
+			signature: T::FeederSignature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			Self::check_payload(&payload, &signature)?;
+			ensure!(
+				payload.nonce == Self::feed_nonces(&payload.feeder),
+				Error::<T, I>::InvalidNonce
+			);
+
+			<FeedNonces<T, I>>::insert(&payload.feeder, payload.nonce.saturating_add(1));
+			Self::do_feed_values(payload.feeder, payload.values)?;
+
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_fee(T::MaxFeeders::get()))]
 		pub fn set_fee(
 			origin: OriginFor<T>,
 			#[pallet::compact] fee: BalanceOf<T, I>,
@@ -224,37 +508,427 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Read the combined value of `key`, paying each contributing feeder
+		/// its fee.
+		#[pallet::weight(T::WeightInfo::read_value(T::MaxFeeders::get()))]
+		pub fn read_value(
+			origin: OriginFor<T>,
+			key: T::FeedKey
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_paid_read(&who, &key)?;
+
+			Ok(().into())
+		}
+
+		/// Combine and publish the value of `key` regardless of its deviation
+		/// from the previous value.
+		#[pallet::weight(T::WeightInfo::force_combine(T::MaxFeeders::get()))]
+		pub fn force_combine(
+			origin: OriginFor<T>,
+			key: T::FeedKey
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let (value, contributors) = Self::combine(&key).ok_or(Error::<T, I>::NoValue)?;
+
+			Self::update_combined(&key, value.clone(), contributors);
+
+			Self::deposit_event(Event::CombinedValueForced(key, value));
+
+			Ok(().into())
+		}
+
+		/// Become a feeder by reserving `FeederDeposit`.
+		#[pallet::weight(T::WeightInfo::bond_feeder(T::MaxFeeders::get()))]
+		pub fn bond_feeder(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let deposit = T::FeederDeposit::get();
+			ensure!(!deposit.is_zero(), Error::<T, I>::BondingDisabled);
+
+			let mut feeders = <Feeders<T, I>>::get();
+			let location = feeders.binary_search(&who).err().ok_or(Error::<T, I>::AlreadyFeeder)?;
+			feeders.try_insert(location, who.clone()).map_err(|_| Error::<T, I>::TooManyFeeders)?;
+			T::Currency::reserve(&who, deposit)?;
+
+			<Feeders<T, I>>::put(feeders);
+			<Bonds<T, I>>::insert(&who, deposit);
+
+			Self::deposit_event(Event::FeederBonded(who, deposit));
+
+			Ok(().into())
+		}
+
+		/// Stop being a bonded feeder and release the remaining bond. `keys`
+		/// must be at least the number of keys the feeder has values for.
+		#[pallet::weight(T::WeightInfo::unbond_feeder(T::MaxFeeders::get(), *keys))]
+		#[transactional]
+		pub fn unbond_feeder(
+			origin: OriginFor<T>,
+			#[pallet::compact] keys: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let bond = <Bonds<T, I>>::take(&who).ok_or(Error::<T, I>::NotBonded)?;
+
+			let mut feeders = <Feeders<T, I>>::get();
+			let feeder_count = feeders.len() as u32;
+			if let Ok(location) = feeders.binary_search(&who) {
+				feeders.remove(location);
+				<Feeders<T, I>>::put(feeders);
+			}
+			let removed = Self::remove_values_of(&who, keys)?;
+			T::Currency::unreserve(&who, bond);
+
+			Self::deposit_event(Event::FeederUnbonded(who, bond));
+
+			Ok(Some(T::WeightInfo::unbond_feeder(feeder_count, removed)).into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+		type Call = Call<T, I>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (payload, signature) = match call {
+				Call::feed_values_unsigned(payload, signature) => (payload, signature),
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			if let Err(e) = Self::check_payload(payload, signature) {
+				return match e {
+					Error::<T, I>::PayloadExpired => InvalidTransaction::Stale,
+					Error::<T, I>::FuturePayload => InvalidTransaction::Future,
+					Error::<T, I>::TooManyFeedValues => InvalidTransaction::ExhaustsResources,
+					// invalid signature or not a feeder
+					_ => InvalidTransaction::BadProof,
+				}
+				.into();
+			}
+
+			// payloads ahead of the current nonce wait in the pool for the
+			// previous ones
+			let nonce = Self::feed_nonces(&payload.feeder);
+			if payload.nonce < nonce {
+				return InvalidTransaction::Stale.into();
+			}
+
+			let expires_at = payload.block_number.saturating_add(T::FeedPayloadLongevity::get());
+			let longevity = expires_at
+				.saturating_sub(frame_system::Pallet::<T>::block_number())
+				.unique_saturated_into();
+
+			let mut builder = ValidTransaction::with_tag_prefix("OracleFeedPayload")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((payload.feeder.clone(), payload.nonce))
+				.longevity(longevity)
+				.propagate(true);
+			if payload.nonce > nonce {
+				builder = builder.and_requires((payload.feeder.clone(), payload.nonce - 1));
+			}
+			builder.build()
+		}
 	}
 }
 
 impl <T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Weight of feeding `keys` values and combining them in `on_finalize`.
+	fn feed_values_weight(keys: u32, feeders: u32) -> Weight {
+		T::WeightInfo::feed_values(keys, feeders)
+			.saturating_add(T::WeightInfo::combine(feeders).saturating_mul(keys as Weight))
+	}
+
+	/// Remove the values of `feeder`, failing if it has values for more than
+	/// `keys` keys. Returns the number of removed values.
+	fn remove_values_of(feeder: &T::AccountId, keys: u32) -> Result<u32, DispatchError> {
+		let count = <AllValue<T, I>>::iter_prefix(feeder)
+			.take(keys.saturating_add(1) as usize)
+			.count();
+		ensure!(count <= keys as usize, Error::<T, I>::TooManyKeys);
+		Ok(Self::clear_values_of(feeder))
+	}
+
+	/// Remove all values of `feeder` with their pending checks, it no longer
+	/// contributes to the combined values. Returns the number of removed
+	/// values.
+	pub(crate) fn clear_values_of(feeder: &T::AccountId) -> u32 {
+		let mut removed = 0u32;
+		for (key, _) in <AllValue<T, I>>::drain_prefix(feeder) {
+			<PendingChecks<T, I>>::remove(&key, feeder);
+			<Contributors<T, I>>::mutate(&key, |contributors| {
+				if let Some(location) = contributors.iter().position(|x| x == feeder) {
+					contributors.remove(location);
+				}
+			});
+			removed = removed.saturating_add(1);
+		}
+		removed
+	}
+
+	/// Bytes a feeder signs for `payload`: the payload tagged with
+	/// `FEED_PAYLOAD_TAG`, the name of the oracle instance and the genesis
+	/// hash, so that it cannot be replayed on another instance or chain.
+	pub fn signing_payload(payload: &FeedPayloadOf<T, I>) -> Vec<u8> {
+		let instance = T::PalletInfo::name::<Self>().unwrap_or("Oracle");
+		let genesis = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+		(FEED_PAYLOAD_TAG, instance.as_bytes(), genesis, payload).encode()
+	}
+
+	/// Check that `payload` is signed by a feeder and is not expired. The
+	/// nonce is checked by the caller.
+	fn check_payload(payload: &FeedPayloadOf<T, I>, signature: &T::FeederSignature) -> Result<(), Error<T, I>> {
+		ensure!(
+			payload.values.len() <= T::MaxFeedValues::get() as usize,
+			Error::<T, I>::TooManyFeedValues
+		);
+		ensure!(
+			signature.verify(&Self::signing_payload(payload)[..], &payload.feeder),
+			Error::<T, I>::InvalidSignature
+		);
+		ensure!(
+			<Feeders<T, I>>::get().binary_search(&payload.feeder).is_ok(),
+			Error::<T, I>::NoPermission
+		);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(payload.block_number <= now, Error::<T, I>::FuturePayload);
+		ensure!(
+			now < payload.block_number.saturating_add(T::FeedPayloadLongevity::get()),
+			Error::<T, I>::PayloadExpired
+		);
+		Ok(())
+	}
+
+	/// Store the values fed by `who`, applying `FeedPolicy` to keys already fed
+	/// by `who` in the block or earlier in `values`.
 	pub fn do_feed_values(who: T::AccountId, values: Vec<(T::FeedKey, T::FeedValue)>) -> DispatchResult {
+		let policy = T::FeedPolicy::get();
+		if policy == FeedPolicy::Reject {
+			// checked upfront so that nothing is stored on rejection
+			ensure!(
+				values.iter().enumerate().all(|(i, (key, _))| {
+					!<FeedCount<T, I>>::contains_key(key, &who) && values[..i].iter().all(|(x, _)| x != key)
+				}),
+				Error::<T, I>::AlreadyFeeded
+			);
+		}
+
 		let now = T::Time::now();
+		let bonded = <Bonds<T, I>>::contains_key(&who);
 		for (key, value) in &values {
+			let count = Self::feed_count(key, &who);
+			let value = match <AllValue<T, I>>::get(&who, key) {
+				Some(prev) if policy == FeedPolicy::Merge && count > 0 => {
+					let total = prev
+						.value
+						.into_linear()
+						.saturating_mul(count.into())
+						.saturating_add(value.into_linear());
+					T::FeedValue::from_linear(total / (u128::from(count) + 1))
+				}
+				_ => value.clone(),
+			};
 			let timestamped_value =  TimestampedValue {
-				value: value.clone(),
+				value,
 				timestamp: now
 			};
-			<IsCombined<T, I>>::remove(&key);
-			<AllValue<T, I>>::insert(&who, &key, timestamped_value)
+			<UpdatedKeys<T, I>>::insert(&key, ());
+			<FeedCount<T, I>>::insert(&key, &who, count.saturating_add(1));
+			<AllValue<T, I>>::insert(&who, &key, timestamped_value);
+			if bonded {
+				<PendingChecks<T, I>>::insert(&key, &who, ());
+			}
 		}
-		<HasFeeded<T, I>>::insert(&who, true);
 
 		Self::deposit_event(Event::NewFeedData(who, values));
 		Ok(())
 	}
 
+	/// Latest combined value of `key`.
 	pub fn get(
 		key: &<T as Config<I>>::FeedKey,
 	) -> Option<TimestampedValueOf<T, I>> {
-		if <IsCombined<T, I>>::get(&key) {
-			<Values<T, I>>::get(key)
-		} else {
-			let timestamped = Self::combine(key)?;
-			<Values<T, I>>::insert(key, timestamped.clone());
-			IsCombined::<T, I>::insert(key, true);
-			Some(timestamped)
+		<Values<T, I>>::get(key)
+	}
+
+	/// Latest combined value of `key`, failing if it is older than `max_age`.
+	pub fn get_fresh(
+		key: &T::FeedKey,
+		max_age: MomentOf<T, I>,
+	) -> Result<TimestampedValueOf<T, I>, DispatchError> {
+		let value = Self::get(key).ok_or(Error::<T, I>::NoValue)?;
+		ensure!(
+			T::Time::now().saturating_sub(value.timestamp) <= max_age,
+			Error::<T, I>::StaleValue
+		);
+		Ok(value)
+	}
+
+	/// Combine the raw values of `key` and publish the result unless it
+	/// deviates more than `MaxDeviation` from the previous value. Nobody
+	/// contributes to a value that is not combined from the raw values.
+	fn combine_and_publish(key: &T::FeedKey) {
+		let prev = Self::values(key);
+		let (timestamped, contributors) = match Self::combine(key) {
+			Some(combined) => combined,
+			None => {
+				<Contributors<T, I>>::remove(key);
+				return;
+			}
+		};
+
+		if let Some(prev) = prev {
+			if prev == timestamped {
+				Self::set_contributors(key, contributors);
+				return;
+			}
+			if timestamped.value.deviation(&prev.value) > T::MaxDeviation::get() {
+				<Contributors<T, I>>::remove(key);
+				Self::deposit_event(Event::CombinedValueRejected(key.clone(), prev, timestamped));
+				return;
+			}
+		}
+
+		Self::update_combined(key, timestamped, contributors);
+	}
+
+	/// Publish a newly combined value of `key`.
+	fn update_combined(key: &T::FeedKey, value: TimestampedValueOf<T, I>, contributors: Vec<T::AccountId>) {
+		Self::push_history(key, value.clone());
+		Self::set_contributors(key, contributors);
+		<Values<T, I>>::insert(key, value.clone());
+		Self::slash_deviating(key, &value);
+	}
+
+	fn set_contributors(key: &T::FeedKey, contributors: Vec<T::AccountId>) {
+		// bounded by the feeders the values are read from
+		<Contributors<T, I>>::insert(key, BoundedVec::try_from(contributors).unwrap_or_default());
+	}
+
+	fn push_history(key: &T::FeedKey, value: TimestampedValueOf<T, I>) {
+		let depth = T::HistoryDepth::get();
+		if depth.is_zero() {
+			return;
+		}
+		let count = Self::history_count(key);
+		<History<T, I>>::insert(key, count % depth, value);
+		<HistoryCount<T, I>>::insert(key, count.saturating_add(1));
+	}
+
+	/// Past combined values of `key`, oldest first.
+	pub fn history(key: &T::FeedKey) -> Vec<TimestampedValueOf<T, I>> {
+		let depth = T::HistoryDepth::get();
+		if depth.is_zero() {
+			return vec![];
 		}
+		let count = Self::history_count(key);
+		let len = count.min(depth);
+
+		(count - len..count)
+			.filter_map(|i| <History<T, I>>::get(key, i % depth))
+			.collect()
+	}
+
+	/// Past combined values of `key` with timestamp in `[from, to]`, oldest
+	/// first.
+	pub fn history_between(
+		key: &T::FeedKey,
+		from: MomentOf<T, I>,
+		to: MomentOf<T, I>,
+	) -> Vec<TimestampedValueOf<T, I>> {
+		Self::history(key)
+			.into_iter()
+			.filter(|x| x.timestamp >= from && x.timestamp <= to)
+			.collect()
+	}
+
+	/// Get the combined value of `key` and charge `who` the fees of the
+	/// feeders whose raw values the last combine used. Nothing is charged if
+	/// no feeder contributed.
+	#[transactional]
+	pub fn do_paid_read(
+		who: &T::AccountId,
+		key: &<T as Config<I>>::FeedKey,
+	) -> Result<TimestampedValueOf<T, I>, DispatchError> {
+		let value = Self::get(key).ok_or(Error::<T, I>::NoValue)?;
+
+		for feeder in Self::contributors(key).into_iter() {
+			let fee = Self::fee_of(&feeder);
+			if fee.is_zero() {
+				continue;
+			}
+			T::Currency::transfer(who, &feeder, fee, ExistenceRequirement::KeepAlive)?;
+			Self::deposit_event(Event::FeePaid(who.clone(), feeder, fee));
+		}
+
+		Self::deposit_event(Event::PaidRead(who.clone(), key.clone(), value.clone()));
+		Ok(value)
+	}
+
+	/// Slash bonded feeders whose pending values of `key` deviate from
+	/// `combined` by more than `SlashTolerance`. Feeders whose bond falls
+	/// below `FeederDeposit` are unbonded.
+	fn slash_deviating(key: &T::FeedKey, combined: &TimestampedValueOf<T, I>) {
+		let tolerance = T::SlashTolerance::get();
+		let mut undercollateralized = Vec::new();
+		for (feeder, _) in <PendingChecks<T, I>>::drain_prefix(key) {
+			let bond = match <Bonds<T, I>>::get(&feeder) {
+				Some(bond) => bond,
+				None => continue,
+			};
+			let deviating = <AllValue<T, I>>::get(&feeder, key)
+				.map_or(false, |x| x.value.deviation(&combined.value) > tolerance);
+			if !deviating {
+				continue;
+			}
+
+			let amount = T::SlashFraction::get() * bond;
+			let (imbalance, remaining) = T::Currency::slash_reserved(&feeder, amount);
+			let slashed = amount.saturating_sub(remaining);
+			T::Slash::on_unbalanced(imbalance);
+			let bond = bond.saturating_sub(slashed);
+			<Bonds<T, I>>::insert(&feeder, bond);
+
+			Self::deposit_event(Event::FeederSlashed(feeder.clone(), key.clone(), slashed));
+
+			if bond.is_zero() || bond < T::FeederDeposit::get() {
+				undercollateralized.push(feeder);
+			}
+		}
+
+		// unbonded after draining, the pending checks are cleared with the values
+		for feeder in undercollateralized {
+			Self::force_unbond(&feeder);
+		}
+	}
+
+	/// Unbond `feeder` and remove its values like `unbond_feeder`, registering
+	/// the removal on the block weight.
+	fn force_unbond(feeder: &T::AccountId) {
+		let bond = match <Bonds<T, I>>::take(feeder) {
+			Some(bond) => bond,
+			None => return,
+		};
+		let mut feeders = <Feeders<T, I>>::get();
+		let feeder_count = feeders.len() as u32;
+		if let Ok(location) = feeders.binary_search(feeder) {
+			feeders.remove(location);
+			<Feeders<T, I>>::put(feeders);
+		}
+		let removed = Self::clear_values_of(feeder);
+		T::Currency::unreserve(feeder, bond);
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::unbond_feeder(feeder_count, removed),
+			DispatchClass::Mandatory,
+		);
+
+		Self::deposit_event(Event::FeederUnbonded(feeder.clone(), bond));
+	}
+
+	/// Fee charged by `feeder` for one paid read.
+	pub fn fee_of(feeder: &T::AccountId) -> BalanceOf<T, I> {
+		<Fees<T, I>>::get(feeder).unwrap_or_else(T::OracleFee::get)
 	}
 
 	pub fn get_concrete(
@@ -264,40 +938,99 @@ impl <T: Config<I>, I: 'static> Pallet<T, I> {
 		<AllValue<T, I>>::get(feeder, key)
 	}
 
-	fn get_values(key: &T::FeedKey) -> Vec<TimestampedValueOf<T, I>> {
-		let mut feeders = <Feeders<T, I>>::get();
-		feeders.sort();
-
-		 feeders
+	/// Median of the raw values of `key` from the feeders of
+	/// `PolkaFoundryFeeders`, ignoring the values older than
+	/// `PolkaFoundryExpiresIn`.
+	pub fn get_polkafoundry(key: &T::FeedKey) -> Option<TimestampedValueOf<T, I>> {
+		let feeders = <Feeders<T, I>>::get();
+		let now = T::Time::now();
+		let expires_in = T::PolkaFoundryExpiresIn::get();
+		let values = T::PolkaFoundryFeeders::sorted_members()
 			.iter()
+			.filter(|x| feeders.binary_search(x).is_ok())
 			.filter_map(|x| <AllValue<T, I>>::get(x, key))
+			.filter(|x| x.timestamp + expires_in > now)
+			.collect();
+		orml_traits::data_provider::median(values)
+	}
+
+	/// Values of `key` from `provider_id`, with the feeder for per-feeder
+	/// values. `Concrete` returns the value of `feeder`, `All` the values of
+	/// every feeder.
+	pub fn get_by_provider(
+		provider_id: DataProviderId,
+		key: &T::FeedKey,
+		feeder: Option<T::AccountId>,
+	) -> Vec<(Option<T::AccountId>, TimestampedValueOf<T, I>)> {
+		match provider_id {
+			DataProviderId::Combined => Self::get(key).map(|v| (None, v)).into_iter().collect(),
+			DataProviderId::PolkaFoundry => Self::get_polkafoundry(key).map(|v| (None, v)).into_iter().collect(),
+			DataProviderId::Concrete => feeder
+				.and_then(|x| Self::get_concrete(key, x.clone()).map(|v| (Some(x), v)))
+				.into_iter()
+				.collect(),
+			DataProviderId::All => Self::feeder_values(key)
+				.into_iter()
+				.map(|(x, v)| (Some(x), v))
+				.collect(),
+		}
+	}
+
+	/// Feeders with their fee and whether they fed in the current block,
+	/// that is have a raw value timestamped now. Unlike the fed keys, raw
+	/// values outlive `on_finalize`.
+	pub fn feeder_infos() -> Vec<FeederInfo<T::AccountId, BalanceOf<T, I>>> {
+		let now = T::Time::now();
+		<Feeders<T, I>>::get()
+			.into_iter()
+			.map(|feeder| FeederInfo {
+				fee: Self::fee_of(&feeder),
+				has_feeded: <AllValue<T, I>>::iter_prefix_values(&feeder).any(|x| x.timestamp == now),
+				feeder,
+			})
 			.collect()
 	}
 
-	/// Returns fresh combined value if has update, or latest combined
-	/// value.
-	pub fn get_no_op(key: &T::FeedKey) -> Option<TimestampedValueOf<T, I>> {
-		if Self::is_combined(key) {
-			Self::values(key)
-		} else {
-			Self::combine(key)
+	/// Freshness of `key` for a `CombineData` ignoring values older than
+	/// `expires_in` and requiring `minimum_count` values.
+	pub fn key_info(
+		key: &T::FeedKey,
+		expires_in: MomentOf<T, I>,
+		minimum_count: u32,
+	) -> OracleKeyInfo<MomentOf<T, I>> {
+		let now = T::Time::now();
+		let fresh_feeders = Self::feeder_values(key)
+			.iter()
+			.filter(|(_, x)| x.timestamp + expires_in > now)
+			.count() as u32;
+
+		OracleKeyInfo {
+			last_update: Self::values(key).map(|x| x.timestamp),
+			fresh_feeders,
+			minimum_count,
 		}
 	}
 
+	/// Raw values of `key` with their feeders, ordered by feeder.
+	pub fn feeder_values(key: &T::FeedKey) -> Vec<(T::AccountId, TimestampedValueOf<T, I>)> {
+		<Feeders<T, I>>::get()
+			.iter()
+			.filter_map(|x| <AllValue<T, I>>::get(x, key).map(|v| (x.clone(), v)))
+			.collect()
+	}
+
 	fn get_all_values() -> Vec<(T::FeedKey, Option<TimestampedValueOf<T, I>>)> {
 		<Values<T, I>>::iter()
-			.map(|(key, _)| key)
-			.map(|key| {
-				let v = Self::get_no_op(&key);
-				(key, v)
-			})
+			.map(|(key, v)| (key, Some(v)))
 			.collect()
 	}
 
+	/// Combine the raw values of `key`, with the feeders whose values are
+	/// used.
 	pub fn combine(
 		key: &<T as Config<I>>::FeedKey,
-	) -> Option<TimestampedValueOf<T, I>> {
-		T::CombineData::combine_data(key, Self::get_values(&key), Self::values(&key))
+	) -> Option<(TimestampedValueOf<T, I>, Vec<T::AccountId>)> {
+		T::CombineData::combine_feeder_data(key, Self::feeder_values(&key), Self::values(&key))
 	}
 }
 
@@ -308,8 +1041,8 @@ impl<T: Config<I>, I: 'static> DataProvider<T::FeedKey, TimestampedValueOf<T, I>
 }
 
 impl<T: Config<I>, I: 'static> DataProviderExtended<T::FeedKey, T::AccountId, TimestampedValueOf<T, I>> for Pallet<T, I> {
-	fn get_polkafoundry(key: &T::FeedKey, feeder: T::AccountId) -> Option<TimestampedValueOf<T, I>> {
-		Self::get_concrete(key, feeder)
+	fn get_polkafoundry(key: &T::FeedKey) -> Option<TimestampedValueOf<T, I>> {
+		Self::get_polkafoundry(key)
 	}
 
 	fn get_concrete(key: &T::FeedKey, feeder: T::AccountId) -> Option<TimestampedValueOf<T, I>> {
@@ -321,13 +1054,140 @@ impl<T: Config<I>, I: 'static> DataProviderExtended<T::FeedKey, T::AccountId, Ti
 	}
 }
 
+/// Paid reads charged to the `Payer` account, for use by other pallets.
+///
+/// This is deliberately not a `DataProvider`: every read transfers the fees
+/// of the contributing feeders from `Payer`, so callers have to handle the
+/// error and must not treat it as a side-effect free getter.
+pub struct PaidDataProvider<T, Payer, I = ()>(marker::PhantomData<(T, Payer, I)>);
+
+impl<T, Payer, I> PaidDataProvider<T, Payer, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		Payer: Get<T::AccountId>,
+{
+	/// Get the combined value of `key`, paying the fees of the contributing
+	/// feeders from `Payer`. Nothing is transferred if it fails.
+	pub fn read(key: &T::FeedKey) -> Result<TimestampedValueOf<T, I>, DispatchError> {
+		Pallet::<T, I>::do_paid_read(&Payer::get(), key)
+	}
+}
+
+/// `DataProvider` returning `None` once the combined value is older than
+/// `MaxAge`.
+pub struct FreshDataProvider<T, MaxAge, I = ()>(marker::PhantomData<(T, MaxAge, I)>);
+
+impl<T, MaxAge, I> DataProvider<T::FeedKey, TimestampedValueOf<T, I>> for FreshDataProvider<T, MaxAge, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		MaxAge: Get<MomentOf<T, I>>,
+{
+	fn get(key: &T::FeedKey) -> Option<TimestampedValueOf<T, I>> {
+		Pallet::<T, I>::get_fresh(key, MaxAge::get()).ok()
+	}
+}
+
+/// Follow the members of a membership provider such as a collective. Outgoing
+/// members are removed from the feeders together with their values, even if
+/// bonded, in which case the bond is released. Incoming members are elected
+/// while there is room below `MaxFeeders`. Feeders that are not members, such
+/// as the ones elected by root or bonded, are kept. The removals are
+/// registered on the block weight.
+impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
+	fn change_members_sorted(incoming: &[T::AccountId], outgoing: &[T::AccountId], _new: &[T::AccountId]) {
+		let mut feeders = <Feeders<T, I>>::get();
+
+		for feeder in outgoing {
+			let location = match feeders.binary_search(feeder) {
+				Ok(location) => location,
+				Err(_) => continue,
+			};
+			let feeder_count = feeders.len() as u32;
+			feeders.remove(location);
+			let removed = Self::clear_values_of(feeder);
+			if let Some(bond) = <Bonds<T, I>>::take(feeder) {
+				T::Currency::unreserve(feeder, bond);
+			}
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::remove_feeder(feeder_count, removed),
+				DispatchClass::Mandatory,
+			);
+			Self::deposit_event(Event::RemoveFeeder(feeder.clone()));
+		}
+
+		for feeder in incoming {
+			if let Err(location) = feeders.binary_search(feeder) {
+				if feeders.try_insert(location, feeder.clone()).is_err() {
+					Self::deposit_event(Event::FeederNotElected(feeder.clone()));
+					continue;
+				}
+				Self::deposit_event(Event::NewFeederElected(feeder.clone()));
+			}
+		}
+
+		<Feeders<T, I>>::put(feeders);
+	}
+}
+
+impl<T: Config<I>, I: 'static> InitializeMembers<T::AccountId> for Pallet<T, I> {
+	fn initialize_members(members: &[T::AccountId]) {
+		Self::change_members_sorted(members, &[], members);
+	}
+}
+
 impl<T: Config<I>, I: 'static> DataFeeder<T::FeedKey, T::FeedValue, T::AccountId> for Pallet<T, I> {
+	/// The caller pays for feeding, combining a newly updated key in
+	/// `on_finalize` is registered on the block weight here.
 	fn feed_value(who: T::AccountId, key: T::FeedKey, value: T::FeedValue) -> DispatchResult {
+		let updated = <UpdatedKeys<T, I>>::contains_key(&key);
 		Self::do_feed_values(who, vec![(key, value)])?;
+		if !updated {
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::combine(T::MaxFeeders::get()),
+				DispatchClass::Mandatory,
+			);
+		}
 		Ok(())
 	}
 }
 
+/// Values of `values` fed less than `expires_in` ago, `None` if there are
+/// fewer than `minimum_count`.
+fn fresh_values<T: Config<I>, I: 'static, F>(
+	values: Vec<(F, TimestampedValueOf<T, I>)>,
+	expires_in: MomentOf<T, I>,
+	minimum_count: u32,
+) -> Option<Vec<(F, TimestampedValueOf<T, I>)>> {
+	let now = T::Time::now();
+	let values: Vec<_> = values
+		.into_iter()
+		.filter(|(_, x)| x.timestamp + expires_in > now)
+		.collect();
+
+	let count = values.len() as u32;
+	if count < minimum_count || count == 0 {
+		return None;
+	}
+	Some(values)
+}
+
+fn combine_median<T: Config<I>, I: 'static, F>(
+	values: Vec<(F, TimestampedValueOf<T, I>)>,
+	expires_in: MomentOf<T, I>,
+	minimum_count: u32,
+) -> Option<(TimestampedValueOf<T, I>, Vec<F>)> {
+	let mut values = fresh_values::<T, I, F>(values, expires_in, minimum_count)?;
+	let mid_index = values.len() / 2;
+
+	// Won't panic as `values` ensured not empty.
+	let (_, (_, value), _) = values.select_nth_unstable_by(mid_index, |a, b| a.1.value.cmp(&b.1.value));
+	let value = value.clone();
+
+	Some((value, values.into_iter().map(|(feeder, _)| feeder).collect()))
+}
+
 /// Sort by value and returns median timestamped value.
 /// Returns prev_value if not enough valid values.
 pub struct DefaultCombineData<T, MinimumCount, ExpiresIn, I = ()>(marker::PhantomData<(T, I, MinimumCount, ExpiresIn)>);
@@ -342,25 +1202,299 @@ for DefaultCombineData<T, MinimumCount, ExpiresIn, I>
 {
 	fn combine_data(
 		_key: &<T as Config<I>>::FeedKey,
-		mut values: Vec<TimestampedValueOf<T, I>>,
+		values: Vec<TimestampedValueOf<T, I>>,
 		prev_value: Option<TimestampedValueOf<T, I>>,
 	) -> Option<TimestampedValueOf<T, I>> {
-		let expires_in = ExpiresIn::get();
-		let now = T::Time::now();
+		let values = values.into_iter().map(|x| ((), x)).collect();
+		combine_median::<T, I, ()>(values, ExpiresIn::get(), MinimumCount::get())
+			.map(|(value, _)| value)
+			.or(prev_value)
+	}
+}
+
+impl<T, I, MinimumCount, ExpiresIn>
+CombineFeederData<T::AccountId, <T as Config<I>>::FeedKey, TimestampedValueOf<T, I>>
+for DefaultCombineData<T, MinimumCount, ExpiresIn, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		MinimumCount: Get<u32>,
+		ExpiresIn: Get<MomentOf<T, I>>,
+{
+	fn combine_feeder_data(
+		_key: &<T as Config<I>>::FeedKey,
+		values: Vec<(T::AccountId, TimestampedValueOf<T, I>)>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<(TimestampedValueOf<T, I>, Vec<T::AccountId>)> {
+		combine_median::<T, I, _>(values, ExpiresIn::get(), MinimumCount::get())
+			.or_else(|| prev_value.map(|value| (value, Vec::new())))
+	}
+}
+
+/// Weighted median of the fresh values, where each value counts with the
+/// weight given by `Weights` to its feeder. Values of feeders with zero
+/// weight are ignored.
+/// Returns prev_value if not enough valid values.
+pub struct WeightedMedianCombineData<T, MinimumCount, ExpiresIn, Weights, I = ()>(
+	marker::PhantomData<(T, I, MinimumCount, ExpiresIn, Weights)>,
+);
+
+impl<T, I, MinimumCount, ExpiresIn, Weights>
+CombineFeederData<T::AccountId, <T as Config<I>>::FeedKey, TimestampedValueOf<T, I>>
+for WeightedMedianCombineData<T, MinimumCount, ExpiresIn, Weights, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		MinimumCount: Get<u32>,
+		ExpiresIn: Get<MomentOf<T, I>>,
+		Weights: GetByKey<T::AccountId, u128>,
+{
+	fn combine_feeder_data(
+		_key: &<T as Config<I>>::FeedKey,
+		values: Vec<(T::AccountId, TimestampedValueOf<T, I>)>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<(TimestampedValueOf<T, I>, Vec<T::AccountId>)> {
+		let weighted = values
+			.into_iter()
+			.filter(|(feeder, _)| !Weights::get(feeder).is_zero())
+			.collect();
+		let mut values = match fresh_values::<T, I, _>(weighted, ExpiresIn::get(), MinimumCount::get()) {
+			Some(values) => values,
+			None => return prev_value.map(|value| (value, Vec::new())),
+		};
 
-		values.retain(|x| x.timestamp + expires_in > now);
+		values.sort_by(|a, b| a.1.value.cmp(&b.1.value));
+		let total = values
+			.iter()
+			.fold(0u128, |acc, (feeder, _)| acc.saturating_add(Weights::get(feeder)));
 
-		let count = values.len() as u32;
-		let minimum_count = MinimumCount::get();
-		if count < minimum_count || count == 0 {
-			return prev_value;
+		let mut accumulated = 0u128;
+		let median = values.iter().find_map(|(feeder, value)| {
+			accumulated = accumulated.saturating_add(Weights::get(feeder));
+			if accumulated.saturating_mul(2) >= total {
+				Some(value.clone())
+			} else {
+				None
+			}
+		});
+
+		match median {
+			Some(median) => Some((median, values.into_iter().map(|(feeder, _)| feeder).collect())),
+			None => prev_value.map(|value| (value, Vec::new())),
 		}
+	}
+}
+
+/// Weights feeders by their bond, feeders without bond have zero weight.
+pub struct BondWeight<T, I = ()>(marker::PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> GetByKey<T::AccountId, u128> for BondWeight<T, I> {
+	fn get(feeder: &T::AccountId) -> u128 {
+		Pallet::<T, I>::bonds(feeder).map_or(0, |x| x.unique_saturated_into())
+	}
+}
+
+fn combine_trimmed_mean<T: Config<I>, I: 'static, F>(
+	values: Vec<(F, TimestampedValueOf<T, I>)>,
+	expires_in: MomentOf<T, I>,
+	minimum_count: u32,
+	trim_percent: Percent,
+) -> Option<(TimestampedValueOf<T, I>, Vec<F>)> {
+	let mut values = fresh_values::<T, I, F>(values, expires_in, minimum_count)?;
+	let count = values.len() as u32;
+
+	values.sort_by(|a, b| a.1.value.cmp(&b.1.value));
+	let trimmed = trim_percent.mul_floor(count).min((count - 1) / 2) as usize;
+	values.truncate(values.len() - trimmed);
+	let kept: Vec<_> = values.into_iter().skip(trimmed).collect();
+
+	let sum = kept.iter().fold(0u128, |acc, (_, x)| acc.saturating_add(x.value.into_linear()));
+	let timestamp = kept.iter().map(|(_, x)| x.timestamp).min()?;
+	let value = TimestampedValue {
+		value: T::FeedValue::from_linear(sum / kept.len() as u128),
+		timestamp,
+	};
+
+	Some((value, kept.into_iter().map(|(feeder, _)| feeder).collect()))
+}
+
+/// Mean of the fresh values after dropping `TrimPercent` of the values at
+/// each end, timestamped with the oldest remaining value.
+/// Returns prev_value if not enough valid values.
+pub struct TrimmedMeanCombineData<T, MinimumCount, ExpiresIn, TrimPercent, I = ()>(
+	marker::PhantomData<(T, I, MinimumCount, ExpiresIn, TrimPercent)>,
+);
+
+impl<T, I, MinimumCount, ExpiresIn, TrimPercent> CombineData<<T as Config<I>>::FeedKey, TimestampedValueOf<T, I>>
+for TrimmedMeanCombineData<T, MinimumCount, ExpiresIn, TrimPercent, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		MinimumCount: Get<u32>,
+		ExpiresIn: Get<MomentOf<T, I>>,
+		TrimPercent: Get<Percent>,
+{
+	fn combine_data(
+		_key: &<T as Config<I>>::FeedKey,
+		values: Vec<TimestampedValueOf<T, I>>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<TimestampedValueOf<T, I>> {
+		let values = values.into_iter().map(|x| ((), x)).collect();
+		combine_trimmed_mean::<T, I, ()>(values, ExpiresIn::get(), MinimumCount::get(), TrimPercent::get())
+			.map(|(value, _)| value)
+			.or(prev_value)
+	}
+}
 
-		let mid_index = count / 2;
+impl<T, I, MinimumCount, ExpiresIn, TrimPercent>
+CombineFeederData<T::AccountId, <T as Config<I>>::FeedKey, TimestampedValueOf<T, I>>
+for TrimmedMeanCombineData<T, MinimumCount, ExpiresIn, TrimPercent, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		MinimumCount: Get<u32>,
+		ExpiresIn: Get<MomentOf<T, I>>,
+		TrimPercent: Get<Percent>,
+{
+	fn combine_feeder_data(
+		_key: &<T as Config<I>>::FeedKey,
+		values: Vec<(T::AccountId, TimestampedValueOf<T, I>)>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<(TimestampedValueOf<T, I>, Vec<T::AccountId>)> {
+		combine_trimmed_mean::<T, I, _>(values, ExpiresIn::get(), MinimumCount::get(), TrimPercent::get())
+			.or_else(|| prev_value.map(|value| (value, Vec::new())))
+	}
+}
 
-		// Won't panic as `values` ensured not empty.
-		let (_, value, _) = values.select_nth_unstable_by(mid_index as usize, |a, b| a.value.cmp(&b.value));
+fn combine_time_weighted<T: Config<I>, I: 'static, F>(
+	values: Vec<(F, TimestampedValueOf<T, I>)>,
+	prev_value: Option<TimestampedValueOf<T, I>>,
+	expires_in: MomentOf<T, I>,
+	minimum_count: u32,
+	window: MomentOf<T, I>,
+) -> Option<(TimestampedValueOf<T, I>, Vec<F>)> {
+	let (spot, feeders) = match combine_median::<T, I, F>(values, expires_in, minimum_count) {
+		Some(spot) => spot,
+		None => return prev_value.map(|value| (value, Vec::new())),
+	};
+	let prev = match prev_value {
+		Some(prev) if prev != spot => prev,
+		_ => return Some((spot, feeders)),
+	};
+
+	if window.is_zero() {
+		return Some((spot, feeders));
+	}
+	let elapsed = spot.timestamp.saturating_sub(prev.timestamp).min(window);
+	let weight = Perquintill::from_rational_approximation(
+		elapsed.unique_saturated_into(),
+		window.unique_saturated_into(),
+	);
+
+	let from = prev.value.into_linear();
+	let to = spot.value.into_linear();
+	let value = if to >= from {
+		from.saturating_add(weight * (to - from))
+	} else {
+		from.saturating_sub(weight * (from - to))
+	};
+
+	let value = TimestampedValue {
+		value: T::FeedValue::from_linear(value),
+		timestamp: spot.timestamp,
+	};
+	Some((value, feeders))
+}
+
+/// Blend the previous combined value with the median of the fresh values,
+/// weighting the median by the time elapsed since the previous value relative
+/// to `Window`. A single block of manipulated feeds can only move the result
+/// by a small fraction.
+pub struct TimeWeightedCombineData<T, MinimumCount, ExpiresIn, Window, I = ()>(
+	marker::PhantomData<(T, I, MinimumCount, ExpiresIn, Window)>,
+);
+
+impl<T, I, MinimumCount, ExpiresIn, Window> CombineData<<T as Config<I>>::FeedKey, TimestampedValueOf<T, I>>
+for TimeWeightedCombineData<T, MinimumCount, ExpiresIn, Window, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		MinimumCount: Get<u32>,
+		ExpiresIn: Get<MomentOf<T, I>>,
+		Window: Get<MomentOf<T, I>>,
+{
+	fn combine_data(
+		_key: &<T as Config<I>>::FeedKey,
+		values: Vec<TimestampedValueOf<T, I>>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<TimestampedValueOf<T, I>> {
+		let values = values.into_iter().map(|x| ((), x)).collect();
+		combine_time_weighted::<T, I, ()>(values, prev_value, ExpiresIn::get(), MinimumCount::get(), Window::get())
+			.map(|(value, _)| value)
+	}
+}
+
+impl<T, I, MinimumCount, ExpiresIn, Window>
+CombineFeederData<T::AccountId, <T as Config<I>>::FeedKey, TimestampedValueOf<T, I>>
+for TimeWeightedCombineData<T, MinimumCount, ExpiresIn, Window, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		MinimumCount: Get<u32>,
+		ExpiresIn: Get<MomentOf<T, I>>,
+		Window: Get<MomentOf<T, I>>,
+{
+	fn combine_feeder_data(
+		_key: &<T as Config<I>>::FeedKey,
+		values: Vec<(T::AccountId, TimestampedValueOf<T, I>)>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<(TimestampedValueOf<T, I>, Vec<T::AccountId>)> {
+		combine_time_weighted::<T, I, _>(values, prev_value, ExpiresIn::get(), MinimumCount::get(), Window::get())
+	}
+}
+
+/// `DataProvider` returning the time weighted average of the combined value
+/// history over the last `Window`. Each historical value is weighted by the
+/// time it stayed the latest value.
+pub struct TwapDataProvider<T, Window, I = ()>(marker::PhantomData<(T, Window, I)>);
+
+impl<T, Window, I> DataProvider<T::FeedKey, TimestampedValueOf<T, I>> for TwapDataProvider<T, Window, I>
+	where
+		T: Config<I>,
+		I: 'static,
+		Window: Get<MomentOf<T, I>>,
+{
+	fn get(key: &T::FeedKey) -> Option<TimestampedValueOf<T, I>> {
+		let latest = Pallet::<T, I>::get(key)?;
+		let now = T::Time::now();
+		let start = now.saturating_sub(Window::get());
+		let history = Pallet::<T, I>::history(key);
+
+		let spans: Vec<(u128, u128)> = history
+			.iter()
+			.enumerate()
+			.filter_map(|(i, x)| {
+				let begin = x.timestamp.max(start);
+				let end = history.get(i + 1).map_or(now, |next| next.timestamp).min(now);
+				if end > begin {
+					Some((x.value.into_linear(), (end - begin).unique_saturated_into()))
+				} else {
+					None
+				}
+			})
+			.collect();
+
+		let total = spans.iter().fold(0u128, |acc, (_, span)| acc.saturating_add(*span));
+		if total.is_zero() {
+			return Some(latest);
+		}
+		let average = spans.into_iter().fold(0u128, |acc, (value, span)| {
+			acc.saturating_add(Perquintill::from_rational_approximation(span, total) * value)
+		});
 
-		Some(value.clone())
+		Some(TimestampedValue {
+			value: T::FeedValue::from_linear(average),
+			timestamp: latest.timestamp,
+		})
 	}
 }
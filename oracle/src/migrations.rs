@@ -0,0 +1,60 @@
+//! Storage migrations for the oracle module.
+
+use super::*;
+use frame_support::{storage::migration::remove_storage_prefix, traits::PalletInfo};
+
+/// Storage releases of the oracle module.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+	/// `Feeders` is an unbounded `Vec`, values are combined on read with
+	/// `HasFeeded` and `IsCombined`.
+	V1,
+	/// `Feeders` is a `BoundedVec` limited by `MaxFeeders`.
+	V2,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1
+	}
+}
+
+/// Migrate `Feeders` into a `BoundedVec`. Feeders beyond `MaxFeeders` are
+/// removed together with their values and pending checks, and their bonds
+/// are released. The
+/// `HasFeeded` and `IsCombined` items left over from combining on read are
+/// removed.
+pub fn migrate_to_bounded_feeders<T: Config<I>, I: 'static>() -> Weight {
+	if <StorageVersion<T, I>>::get() != Releases::V1 {
+		return 0;
+	}
+
+	let mut removed = Vec::new();
+	let _ = <Feeders<T, I>>::translate::<Vec<T::AccountId>, _>(|feeders| {
+		let mut feeders = feeders.unwrap_or_default();
+		feeders.sort();
+		let max = T::MaxFeeders::get() as usize;
+		if feeders.len() > max {
+			removed = feeders.split_off(max);
+		}
+		Some(BoundedVec::try_from(feeders).unwrap_or_default())
+	});
+
+	let mut writes: Weight = 2;
+	for feeder in removed.iter() {
+		let values = Pallet::<T, I>::clear_values_of(feeder);
+		if let Some(bond) = <Bonds<T, I>>::take(feeder) {
+			T::Currency::unreserve(feeder, bond);
+		}
+		writes = writes.saturating_add(2 * values as Weight).saturating_add(2);
+	}
+
+	let pallet = T::PalletInfo::name::<Pallet<T, I>>().unwrap_or("Oracle");
+	remove_storage_prefix(pallet.as_bytes(), b"HasFeeded", &[]);
+	remove_storage_prefix(pallet.as_bytes(), b"IsCombined", &[]);
+	writes = writes.saturating_add(2);
+
+	<StorageVersion<T, I>>::put(Releases::V2);
+
+	T::DbWeight::get().reads_writes(2 + removed.len() as Weight, writes)
+}
@@ -2,13 +2,17 @@ use super::*;
 use crate as oracle;
 
 use frame_support::{
-	construct_runtime, parameter_types,
+	construct_runtime, instances::Instance1, parameter_types,
+	traits::SortedMembers,
+	weights::constants::RocksDbWeight,
 };
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendTransactionTypes, SigningTypes};
 use sp_runtime::{
-	testing::{Header},
+	testing::{Header, TestSignature, TestXt, UintAuthorityId},
 	traits::{IdentityLookup, BlakeTwo256},
 };
 use sp_std::cell::RefCell;
+use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 
 /// The AccountId alias in this test module.
@@ -20,6 +24,7 @@ pub(crate) type Value = u32;
 pub const POLKAFOUNDRY: u64 = 1;
 pub const ALICE: u64 = 2;
 pub const BOB: u64 = 3;
+pub const CHARLIE: u64 = 4;
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
@@ -48,7 +53,7 @@ impl frame_system::Config for Test {
 	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
-	type DbWeight = ();
+	type DbWeight = RocksDbWeight;
 	type BaseCallFilter = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ();
@@ -73,6 +78,8 @@ impl pallet_balances::Config for Test {
 
 thread_local! {
 	static TIME: RefCell<u32> = RefCell::new(0);
+	static MAX_DEVIATION: RefCell<Permill> = RefCell::new(Permill::one());
+	static FEED_POLICY: RefCell<FeedPolicy> = RefCell::new(FeedPolicy::Reject);
 }
 
 pub struct Timestamp;
@@ -90,10 +97,101 @@ impl Timestamp {
 	}
 }
 
+pub struct MaxDeviation;
+impl Get<Permill> for MaxDeviation {
+	fn get() -> Permill {
+		MAX_DEVIATION.with(|v| *v.borrow())
+	}
+}
+
+impl MaxDeviation {
+	pub fn set(val: Permill) {
+		MAX_DEVIATION.with(|v| *v.borrow_mut() = val);
+	}
+}
+
+pub struct CurrentFeedPolicy;
+impl Get<FeedPolicy> for CurrentFeedPolicy {
+	fn get() -> FeedPolicy {
+		FEED_POLICY.with(|v| *v.borrow())
+	}
+}
+
+impl CurrentFeedPolicy {
+	pub fn set(val: FeedPolicy) {
+		FEED_POLICY.with(|v| *v.borrow_mut() = val);
+	}
+}
+
 parameter_types! {
 	pub const MinimumCount: u32 = 3;
 	pub const ExpiresIn: u32 = 600;
 	pub const Fee: u64 = 100;
+	pub const CharliePays: AccountId = CHARLIE;
+	pub const FeederDeposit: u64 = 100;
+	pub const SlashTolerance: Permill = Permill::from_percent(10);
+	pub const SlashFraction: Perbill = Perbill::from_percent(50);
+	pub const HistoryDepth: u32 = 3;
+	pub const TwapWindow: u32 = 1000;
+	pub const MaxAge: u32 = 600;
+	pub const FeedPayloadLongevity: u64 = 5;
+	pub const UnsignedPriority: u64 = 1 << 20;
+	pub const TrimPercent: Percent = Percent::from_percent(25);
+	pub const MaxFeeders: u32 = 5;
+	pub const MaxFeedValues: u32 = 10;
+}
+
+parameter_type_with_key! {
+	pub FeederWeights: |feeder: AccountId| -> u128 {
+		match *feeder {
+			POLKAFOUNDRY => 3,
+			ALICE | BOB => 1,
+			_ => 0,
+		}
+	};
+}
+
+pub struct PolkaFoundryFeeders;
+impl SortedMembers<AccountId> for PolkaFoundryFeeders {
+	fn sorted_members() -> Vec<AccountId> {
+		vec![POLKAFOUNDRY, CHARLIE]
+	}
+}
+
+pub struct TestAuthId;
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+pub type Extrinsic = TestXt<Call, ()>;
+
+impl SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> SendTransactionTypes<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		_public: UintAuthorityId,
+		account: AccountId,
+		_nonce: u64,
+	) -> Option<(Call, <Extrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+		Some((call, (account, ())))
+	}
 }
 
 impl Config for Test {
@@ -104,6 +202,50 @@ impl Config for Test {
 	type FeedValue = Value;
 	type Currency = Balances;
 	type OracleFee = Fee;
+	type FeederDeposit = FeederDeposit;
+	type SlashTolerance = SlashTolerance;
+	type SlashFraction = SlashFraction;
+	type Slash = ();
+	type HistoryDepth = HistoryDepth;
+	type MaxDeviation = MaxDeviation;
+	type MaxFeeders = MaxFeeders;
+	type MaxFeedValues = MaxFeedValues;
+	type PolkaFoundryFeeders = PolkaFoundryFeeders;
+	type PolkaFoundryExpiresIn = ExpiresIn;
+	type FeedPolicy = CurrentFeedPolicy;
+	type FeederPublic = UintAuthorityId;
+	type FeederSignature = TestSignature;
+	type FeedPayloadLongevity = FeedPayloadLongevity;
+	type UnsignedPriority = UnsignedPriority;
+	type OffchainFeeder = HttpFeeder<Self, TestAuthId>;
+	type WeightInfo = ();
+}
+
+impl Config<Instance1> for Test {
+	type Event = Event;
+	type CombineData = DefaultCombineData<Self, MinimumCount, ExpiresIn, Instance1>;
+	type Time = Timestamp;
+	type FeedKey = Key;
+	type FeedValue = Value;
+	type Currency = Balances;
+	type OracleFee = Fee;
+	type FeederDeposit = FeederDeposit;
+	type SlashTolerance = SlashTolerance;
+	type SlashFraction = SlashFraction;
+	type Slash = ();
+	type HistoryDepth = HistoryDepth;
+	type MaxDeviation = MaxDeviation;
+	type MaxFeeders = MaxFeeders;
+	type MaxFeedValues = MaxFeedValues;
+	type PolkaFoundryFeeders = PolkaFoundryFeeders;
+	type PolkaFoundryExpiresIn = ExpiresIn;
+	type FeedPolicy = CurrentFeedPolicy;
+	type FeederPublic = UintAuthorityId;
+	type FeederSignature = TestSignature;
+	type FeedPayloadLongevity = FeedPayloadLongevity;
+	type UnsignedPriority = UnsignedPriority;
+	type OffchainFeeder = ();
+	type WeightInfo = ();
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -116,19 +258,22 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		Oracle: oracle::{Pallet, Storage, Call, Config<T>, Event<T>},
+		Oracle: oracle::{Pallet, Storage, Call, Config<T>, Event<T>, ValidateUnsigned},
+		OracleB: oracle::<Instance1>::{Pallet, Storage, Call, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
 pub struct ExtBuilder {
 	feeders: Vec<AccountId>,
+	balances: Vec<(AccountId, Balance)>,
 }
 
 impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			feeders: vec![],
+			balances: vec![],
 		}
 	}
 }
@@ -139,6 +284,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
 	pub fn alice_is_feeder(mut self) -> Self {
 		self.feeders = vec![ALICE];
 		self
@@ -149,6 +299,12 @@ impl ExtBuilder {
 			.build_storage::<Test>()
 			.unwrap();
 
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.balances,
+		}
+			.assimilate_storage(&mut t)
+			.unwrap();
+
 		oracle::GenesisConfig::<Test> {
 			feeders: self.feeders,
 			_phantom: Default::default()
@@ -0,0 +1,202 @@
+//! Offchain worker feeding values fetched over HTTP.
+//!
+//! The sources are read from the persistent offchain storage under
+//! [`sources_key`], as a SCALE encoded `Vec<(FeedKey, PriceSource)>`. Values
+//! are submitted with `feed_values`, signed by the first key of the local
+//! keystore that belongs to a feeder.
+
+use super::*;
+use frame_support::traits::PalletInfo;
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+use lite_json::json::{JsonValue, NumberValue};
+use sp_runtime::{
+	offchain::{http, storage::StorageValueRef, Duration},
+	traits::IdentifyAccount,
+	RuntimeAppPublic,
+};
+
+/// Timeout of a single HTTP request in milliseconds.
+pub const FETCH_TIMEOUT: u64 = 2_000;
+
+/// Location of a value of a feed key.
+#[derive(Encode, Decode, RuntimeDebug, Eq, PartialEq, Clone)]
+pub struct PriceSource {
+	/// URL returning a JSON document
+	pub url: Vec<u8>,
+	/// Dot separated path of the number in the document, e.g. `bitcoin.usd`.
+	/// Array elements are selected by index.
+	pub path: Vec<u8>,
+	/// Decimals of the linear representation of the fed value
+	pub decimals: u32,
+}
+
+/// Feeds values from an offchain worker.
+pub trait OffchainFeeder<BlockNumber> {
+	fn feed(now: BlockNumber);
+}
+
+impl<BlockNumber> OffchainFeeder<BlockNumber> for () {
+	fn feed(_now: BlockNumber) {}
+}
+
+#[derive(RuntimeDebug, Eq, PartialEq)]
+pub enum OffchainErr {
+	/// No sources are configured
+	NoSources,
+	/// No local key belongs to a feeder
+	NoFeederKey,
+	/// HTTP request failed or returned an unexpected status
+	Http,
+	/// Response is not valid JSON or has no number at the path
+	Json,
+	/// Transaction could not be submitted
+	SubmitFailed,
+}
+
+/// Offchain storage key of the sources of the oracle instance.
+pub fn sources_key<T: Config<I>, I: 'static>() -> Vec<u8> {
+	let name = T::PalletInfo::name::<Pallet<T, I>>().unwrap_or("Oracle");
+	[b"pkfp-oracle::sources::", name.as_bytes()].concat()
+}
+
+/// `OffchainFeeder` fetching the configured sources over HTTP and feeding them
+/// with a local feeder key of type `AuthorityId`.
+pub struct HttpFeeder<T, AuthorityId, I = ()>(marker::PhantomData<(T, AuthorityId, I)>);
+
+impl<T, AuthorityId, I> OffchainFeeder<T::BlockNumber> for HttpFeeder<T, AuthorityId, I>
+where
+	T: Config<I> + CreateSignedTransaction<Call<T, I>>,
+	AuthorityId: AppCrypto<T::Public, T::Signature>,
+	I: 'static,
+{
+	fn feed(now: T::BlockNumber) {
+		if let Err(e) = Self::fetch_and_feed() {
+			log::warn!(target: "oracle", "offchain feeding failed at {:?}: {:?}", now, e);
+		}
+	}
+}
+
+impl<T, AuthorityId, I> HttpFeeder<T, AuthorityId, I>
+where
+	T: Config<I> + CreateSignedTransaction<Call<T, I>>,
+	AuthorityId: AppCrypto<T::Public, T::Signature>,
+	I: 'static,
+{
+	/// Fetch the configured sources and feed the fetched values, failing
+	/// only if none of them could be fetched.
+	pub fn fetch_and_feed() -> Result<(), OffchainErr> {
+		let sources = StorageValueRef::persistent(&sources_key::<T, I>())
+			.get::<Vec<(T::FeedKey, PriceSource)>>()
+			.ok()
+			.flatten()
+			.filter(|x| !x.is_empty())
+			.ok_or(OffchainErr::NoSources)?;
+
+		let feeder = Self::local_feeder().ok_or(OffchainErr::NoFeederKey)?;
+
+		// a failing source only skips its key
+		let mut error = None;
+		let values = sources
+			.into_iter()
+			.take(T::MaxFeedValues::get() as usize)
+			.filter_map(|(key, source)| match fetch_value(&source) {
+				Ok(value) => Some((key, value)),
+				Err(e) => {
+					log::warn!(target: "oracle", "fetching the value of {:?} failed: {:?}", key, e);
+					error = Some(e);
+					None
+				}
+			})
+			.collect::<Vec<(T::FeedKey, T::FeedValue)>>();
+		if values.is_empty() {
+			return Err(error.unwrap_or(OffchainErr::NoSources));
+		}
+
+		match Signer::<T, AuthorityId>::any_account()
+			.with_filter(vec![feeder])
+			.send_signed_transaction(|_| Call::feed_values(values.clone()))
+		{
+			Some((_, Ok(()))) => Ok(()),
+			_ => Err(OffchainErr::SubmitFailed),
+		}
+	}
+
+	/// First key of the local keystore belonging to a feeder.
+	fn local_feeder() -> Option<T::Public> {
+		let feeders = <Feeders<T, I>>::get();
+		AuthorityId::RuntimeAppPublic::all()
+			.into_iter()
+			.map(|key| {
+				let generic: AuthorityId::GenericPublic = key.into();
+				let public: T::Public = generic.into();
+				public
+			})
+			.find(|public| feeders.binary_search(&public.clone().into_account()).is_ok())
+	}
+}
+
+fn fetch_value<V: LinearValue>(source: &PriceSource) -> Result<V, OffchainErr> {
+	let body = fetch(&source.url)?;
+	let body = sp_std::str::from_utf8(&body).map_err(|_| OffchainErr::Json)?;
+	let json = lite_json::parse_json(body).map_err(|_| OffchainErr::Json)?;
+	match select(&json, &source.path) {
+		Some(JsonValue::Number(number)) => to_linear(number, source.decimals)
+			.map(V::from_linear)
+			.ok_or(OffchainErr::Json),
+		_ => Err(OffchainErr::Json),
+	}
+}
+
+fn fetch(url: &[u8]) -> Result<Vec<u8>, OffchainErr> {
+	let url = sp_std::str::from_utf8(url).map_err(|_| OffchainErr::Http)?;
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT));
+	let pending = http::Request::get(url)
+		.deadline(deadline)
+		.send()
+		.map_err(|_| OffchainErr::Http)?;
+	let response = pending
+		.try_wait(deadline)
+		.map_err(|_| OffchainErr::Http)?
+		.map_err(|_| OffchainErr::Http)?;
+	if response.code != 200 {
+		return Err(OffchainErr::Http);
+	}
+	Ok(response.body().collect())
+}
+
+/// Value at the dot separated `path` of `json`.
+fn select<'a>(json: &'a JsonValue, path: &[u8]) -> Option<&'a JsonValue> {
+	path.split(|x| *x == b'.')
+		.filter(|x| !x.is_empty())
+		.try_fold(json, |value, segment| match value {
+			JsonValue::Object(fields) => fields
+				.iter()
+				.find(|(k, _)| k.iter().copied().eq(segment.iter().map(|x| *x as char)))
+				.map(|(_, v)| v),
+			JsonValue::Array(items) => sp_std::str::from_utf8(segment)
+				.ok()
+				.and_then(|x| x.parse::<usize>().ok())
+				.and_then(|x| items.get(x)),
+			_ => None,
+		})
+}
+
+/// Non-negative `number` scaled by `10^decimals`, truncated.
+fn to_linear(number: &NumberValue, decimals: u32) -> Option<u128> {
+	if number.negative {
+		return None;
+	}
+	let mantissa = (number.integer as u128)
+		.checked_mul(10u128.checked_pow(number.fraction_length)?)?
+		.checked_add(number.fraction as u128)?;
+	let shift = (decimals as i64) + (number.exponent as i64) - (number.fraction_length as i64);
+	if shift >= 0 {
+		mantissa.checked_mul(10u128.checked_pow(shift.try_into().ok()?)?)
+	} else {
+		Some(
+			10u128
+				.checked_pow((-shift).try_into().ok()?)
+				.map_or(0, |divisor| mantissa / divisor),
+		)
+	}
+}
@@ -1,6 +1,16 @@
 use super::*;
-use frame_support::{assert_noop, assert_ok};
-use mock::*;
+use frame_support::{assert_noop, assert_ok, instances::Instance1, storage::migration};
+use mock::{Event, *};
+use offchain::OffchainErr;
+use sp_core::offchain::{
+	testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_runtime::{
+	offchain::storage::StorageValueRef,
+	testing::{TestSignature, UintAuthorityId},
+	traits::BadOrigin,
+};
 
 #[test]
 fn elect_feeder_should_works() {
@@ -101,6 +111,48 @@ fn feed_value_should_work() {
 		})
 }
 
+#[test]
+fn feed_policy_should_work() {
+	ExtBuilder::default()
+		.alice_is_feeder()
+		.build_and_execute(|| {
+			let value = |value| Some(TimestampedValue { value, timestamp: 12345 });
+
+			// other keys can still be fed in the block
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(1, 100)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(2, 100)]));
+			assert_noop!(
+				Oracle::feed_values(Origin::signed(ALICE), vec![(3, 100), (1, 200)]),
+				Error::<Test, _>::AlreadyFeeded
+			);
+			assert_eq!(Oracle::feed_count(1, ALICE), 1);
+			// a key cannot be fed twice in one call either
+			assert_noop!(
+				Oracle::feed_values(Origin::signed(ALICE), vec![(3, 100), (3, 200)]),
+				Error::<Test, _>::AlreadyFeeded
+			);
+			assert_eq!(Oracle::feed_count(3, ALICE), 0);
+
+			CurrentFeedPolicy::set(FeedPolicy::Overwrite);
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(1, 200)]));
+			assert_eq!(Oracle::all_value(ALICE, 1), value(200));
+			assert_eq!(Oracle::feed_count(1, ALICE), 2);
+
+			CurrentFeedPolicy::set(FeedPolicy::Merge);
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(1, 500)]));
+			// mean of 200, 200 and 500
+			assert_eq!(Oracle::all_value(ALICE, 1), value(300));
+
+			Oracle::on_finalize(1);
+			assert_eq!(Oracle::feed_count(1, ALICE), 0);
+			assert_eq!(Oracle::feed_count(2, ALICE), 0);
+
+			// merging starts over in the next block
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(1, 600)]));
+			assert_eq!(Oracle::all_value(ALICE, 1), value(600));
+		})
+}
+
 #[test]
 fn combine_should_work() {
 	ExtBuilder::default()
@@ -112,10 +164,14 @@ fn combine_should_work() {
 
 			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
 			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			Oracle::on_finalize(1);
 			// not enough feed
 			assert_eq!(Oracle::get(&key), None);
 
 			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			// not combined until the end of the block
+			assert_eq!(Oracle::get(&key), None);
+			Oracle::on_finalize(2);
 
 			let expected = Some(TimestampedValue {
 				value: 1200,
@@ -128,30 +184,29 @@ fn combine_should_work() {
 
 			assert_eq!(Oracle::get(&key), expected);
 
-			Oracle::on_finalize(1);
-
 			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 2300)]));
 			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 2000)]));
+			Oracle::on_finalize(3);
 
 			assert_eq!(Oracle::get(&key), expected);
 
-			Oracle::on_finalize(2);
-
 			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 2300)]));
 			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 2000)]));
 			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 2200)]));
+			Oracle::on_finalize(4);
 			let expected2 = Some(TimestampedValue {
 				value: 2200,
 				timestamp: 23456,
 			});
 			assert_eq!(Oracle::get(&key), expected2);
 			Timestamp::set_timestamp(34567);
-			Oracle::on_finalize(2);
 			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 2200)]));
+			Oracle::on_finalize(5);
 
 			Timestamp::set_timestamp(40000);
 			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 2300)]));
 			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 2000)]));
+			Oracle::on_finalize(6);
 			// still old values because bob is expired
 			let expected3 = Some(TimestampedValue {
 				value: 2200,
@@ -161,6 +216,26 @@ fn combine_should_work() {
 		})
 }
 
+#[test]
+fn data_feeder_should_register_combine_weight() {
+	ExtBuilder::default()
+		.alice_is_feeder()
+		.build_and_execute(|| {
+			let combine = <() as WeightInfo>::combine(MaxFeeders::get());
+			let mandatory = || *System::block_weight().get(DispatchClass::Mandatory);
+
+			assert_ok!(<Oracle as DataFeeder<_, _, _>>::feed_value(ALICE, 50, 1000));
+			assert_eq!(mandatory(), combine);
+
+			// the key is combined once per block
+			assert_ok!(<Oracle as DataFeeder<_, _, _>>::feed_value(POLKAFOUNDRY, 50, 1100));
+			assert_eq!(mandatory(), combine);
+
+			assert_ok!(<Oracle as DataFeeder<_, _, _>>::feed_value(ALICE, 51, 1000));
+			assert_eq!(mandatory(), combine * 2);
+		})
+}
+
 #[test]
 fn get_concrete_should_work() {
 	ExtBuilder::default()
@@ -197,6 +272,113 @@ fn get_concrete_should_work() {
 		})
 }
 
+#[test]
+fn get_by_provider_should_work() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB, CHARLIE]
+		)
+		.build_and_execute(|| {
+			let key: u32 = 50;
+			let value = |value| TimestampedValue { value, timestamp: 12345 };
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			Oracle::on_finalize(1);
+
+			assert_eq!(Oracle::get_by_provider(DataProviderId::Combined, &key, None), vec![(None, value(1200))]);
+			// only POLKAFOUNDRY of the first-party feeders has fed
+			assert_eq!(Oracle::get_by_provider(DataProviderId::PolkaFoundry, &key, None), vec![(None, value(1300))]);
+			assert_eq!(
+				Oracle::get_by_provider(DataProviderId::Concrete, &key, Some(ALICE)),
+				vec![(Some(ALICE), value(1000))]
+			);
+			assert_eq!(Oracle::get_by_provider(DataProviderId::Concrete, &key, None), vec![]);
+			assert_eq!(Oracle::get_by_provider(DataProviderId::Concrete, &key, Some(CHARLIE)), vec![]);
+			assert_eq!(
+				Oracle::get_by_provider(DataProviderId::All, &key, None),
+				vec![(Some(POLKAFOUNDRY), value(1300)), (Some(ALICE), value(1000)), (Some(BOB), value(1200))]
+			);
+
+			assert_ok!(Oracle::feed_values(Origin::signed(CHARLIE), vec![(key, 1100)]));
+			// median of the first-party feeders
+			assert_eq!(Oracle::get_polkafoundry(&key), Some(value(1300)));
+			assert_ok!(Oracle::remove_feeder(Origin::root(), POLKAFOUNDRY, 1));
+			assert_eq!(Oracle::get_polkafoundry(&key), Some(value(1100)));
+			assert_eq!(Oracle::get_by_provider(DataProviderId::PolkaFoundry, &999, None), vec![]);
+		})
+}
+
+#[test]
+fn get_polkafoundry_should_ignore_expired_values() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB, CHARLIE]
+		)
+		.build_and_execute(|| {
+			let key: u32 = 50;
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
+			Oracle::on_finalize(1);
+
+			System::set_block_number(2);
+			Timestamp::set_timestamp(12345 + 500);
+			assert_ok!(Oracle::feed_values(Origin::signed(CHARLIE), vec![(key, 1100)]));
+			assert_eq!(Oracle::get_polkafoundry(&key), Some(TimestampedValue { value: 1300, timestamp: 12345 }));
+
+			// the value of POLKAFOUNDRY expires
+			Timestamp::set_timestamp(12345 + ExpiresIn::get());
+			assert_eq!(Oracle::get_polkafoundry(&key), Some(TimestampedValue { value: 1100, timestamp: 12845 }));
+
+			// nothing fresh is left
+			Timestamp::set_timestamp(12845 + ExpiresIn::get());
+			assert_eq!(Oracle::get_polkafoundry(&key), None);
+			assert_eq!(Oracle::get_by_provider(DataProviderId::PolkaFoundry, &key, None), vec![]);
+		})
+}
+
+#[test]
+fn feeder_and_key_infos_should_work() {
+	ExtBuilder::default()
+		.feeders(vec![POLKAFOUNDRY, ALICE, BOB])
+		.build_and_execute(|| {
+			let key: u32 = 50;
+			assert_ok!(Oracle::set_fee(Origin::signed(ALICE), 10));
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
+
+			assert_eq!(
+				Oracle::feeder_infos(),
+				vec![
+					FeederInfo { feeder: POLKAFOUNDRY, fee: 100, has_feeded: true },
+					FeederInfo { feeder: ALICE, fee: 10, has_feeded: false },
+					FeederInfo { feeder: BOB, fee: 100, has_feeded: false },
+				]
+			);
+			assert_eq!(
+				Oracle::key_info(&key, 600, 3),
+				OracleKeyInfo { last_update: None, fresh_feeders: 1, minimum_count: 3 }
+			);
+
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			Oracle::on_finalize(1);
+			assert_eq!(
+				Oracle::key_info(&key, 600, 3),
+				OracleKeyInfo { last_update: Some(12345), fresh_feeders: 3, minimum_count: 3 }
+			);
+			// still fed in the finalized block
+			assert!(Oracle::feeder_infos().iter().all(|x| x.has_feeded));
+
+			Timestamp::set_timestamp(12345 + 600);
+			assert!(Oracle::feeder_infos().iter().all(|x| !x.has_feeded));
+			assert_eq!(
+				Oracle::key_info(&key, 600, 3),
+				OracleKeyInfo { last_update: Some(12345), fresh_feeders: 0, minimum_count: 3 }
+			);
+		})
+}
+
 #[test]
 fn get_all_should_work() {
 	ExtBuilder::default()
@@ -215,22 +397,18 @@ fn get_all_should_work() {
 				Oracle::get_all_values(),
 				vec![]
 			);
-			assert_eq!(Oracle::get(&dot), Some(TimestampedValue {
-				value: 1300,
-				timestamp: 12345,
-			}));
+			Oracle::on_finalize(1);
+
 			let dot_price = Some(TimestampedValue {
 				value: 1300,
 				timestamp: 12345,
 			});
-
-			assert_eq!(Oracle::get_all_values(), vec![(dot, dot_price)]);
+			assert_eq!(Oracle::get(&dot), dot_price);
 
 			let ksm_price = Some(TimestampedValue {
 				value: 12000,
 				timestamp: 12345,
 			});
-
 			assert_eq!(Oracle::get(&ksm), ksm_price);
 
 			assert_eq!(Oracle::get_all_values(), vec![(ksm, ksm_price), (dot, dot_price)]);
@@ -244,7 +422,7 @@ fn remove_feeder_work() {
 			vec![POLKAFOUNDRY, ALICE, BOB]
 		)
 		.build_and_execute(|| {
-			assert_noop!(Oracle::remove_feeder(Origin::root(), 10u64), Error::<Test, _>::NotFeeder);
+			assert_noop!(Oracle::remove_feeder(Origin::root(), 10u64, 0), Error::<Test, _>::NotFeeder);
 			assert_noop!(
 				Oracle::elect_feeder(
 					Origin::root(),
@@ -264,7 +442,8 @@ fn remove_feeder_work() {
 					timestamp: 12345
 				})
 			);
-			assert_ok!(Oracle::remove_feeder(Origin::root(), BOB));
+			assert_noop!(Oracle::remove_feeder(Origin::root(), BOB, 0), Error::<Test, _>::TooManyKeys);
+			assert_ok!(Oracle::remove_feeder(Origin::root(), BOB, 1));
 			assert_eq!(
 				Oracle::get_concrete(&key, BOB),
 				None
@@ -277,3 +456,980 @@ fn remove_feeder_work() {
 			);
 		})
 }
+
+#[test]
+fn read_value_should_pay_contributing_feeders() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB]
+		)
+		.balances(vec![(CHARLIE, 1000)])
+		.build_and_execute(|| {
+			let key: u32 = 50;
+
+			assert_noop!(Oracle::read_value(Origin::signed(CHARLIE), key), Error::<Test, _>::NoValue);
+
+			assert_ok!(Oracle::set_fee(Origin::signed(ALICE), 50));
+			assert_ok!(Oracle::set_fee(Origin::signed(BOB), 0));
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			Oracle::on_finalize(1);
+
+			assert_ok!(Oracle::read_value(Origin::signed(CHARLIE), key));
+
+			assert_eq!(Balances::free_balance(CHARLIE), 850);
+			assert_eq!(Balances::free_balance(POLKAFOUNDRY), 100);
+			assert_eq!(Balances::free_balance(ALICE), 50);
+			assert_eq!(Balances::free_balance(BOB), 0);
+
+			System::assert_has_event(Event::Oracle(crate::Event::FeePaid(CHARLIE, POLKAFOUNDRY, 100)));
+			System::assert_has_event(Event::Oracle(crate::Event::FeePaid(CHARLIE, ALICE, 50)));
+			System::assert_last_event(Event::Oracle(crate::Event::PaidRead(
+				CHARLIE,
+				key,
+				TimestampedValue {
+					value: 1200,
+					timestamp: 12345
+				}
+			)));
+		})
+}
+
+#[test]
+fn read_value_should_be_free_without_feeders() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB]
+		)
+		.balances(vec![(CHARLIE, 1000)])
+		.build_and_execute(|| {
+			let key: u32 = 50;
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			Oracle::on_finalize(1);
+			assert!(Oracle::get(&key).is_some());
+
+			assert_ok!(Oracle::remove_feeder(Origin::root(), POLKAFOUNDRY, 1));
+			assert_ok!(Oracle::remove_feeder(Origin::root(), ALICE, 1));
+			assert_ok!(Oracle::remove_feeder(Origin::root(), BOB, 1));
+			assert_eq!(Oracle::contributors(&key).to_vec(), Vec::<AccountId>::new());
+
+			assert_eq!(
+				Oracle::do_paid_read(&CHARLIE, &key),
+				Ok(TimestampedValue {
+					value: 1200,
+					timestamp: 12345
+				})
+			);
+			assert_eq!(Balances::free_balance(CHARLIE), 1000);
+		})
+}
+
+#[test]
+fn read_value_should_not_pay_for_previous_value() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB]
+		)
+		.balances(vec![(CHARLIE, 1000)])
+		.build_and_execute(|| {
+			let key: u32 = 50;
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			Oracle::on_finalize(1);
+			assert_eq!(Oracle::contributors(&key).to_vec(), vec![POLKAFOUNDRY, ALICE, BOB]);
+
+			// not enough fresh values, the previous value is kept
+			System::set_block_number(2);
+			Timestamp::set_timestamp(12345 + ExpiresIn::get());
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			Oracle::on_finalize(2);
+			assert_eq!(Oracle::get(&key), Some(TimestampedValue { value: 1200, timestamp: 12345 }));
+			assert_eq!(Oracle::contributors(&key).to_vec(), Vec::<AccountId>::new());
+
+			assert_ok!(Oracle::read_value(Origin::signed(CHARLIE), key));
+			assert_eq!(Balances::free_balance(CHARLIE), 1000);
+			assert_eq!(Balances::free_balance(ALICE), 0);
+		})
+}
+
+#[test]
+fn read_value_should_not_pay_partially() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB]
+		)
+		.balances(vec![(CHARLIE, 150)])
+		.build_and_execute(|| {
+			let key: u32 = 50;
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			Oracle::on_finalize(1);
+
+			assert_noop!(
+				Oracle::read_value(Origin::signed(CHARLIE), key),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+			assert_eq!(Balances::free_balance(POLKAFOUNDRY), 0);
+			assert_noop!(
+				PaidDataProvider::<Test, CharliePays>::read(&key),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+		})
+}
+
+#[test]
+fn bond_feeder_should_work() {
+	ExtBuilder::default()
+		.alice_is_feeder()
+		.balances(vec![(ALICE, 1000), (CHARLIE, 1000)])
+		.build_and_execute(|| {
+			assert_noop!(Oracle::bond_feeder(Origin::signed(ALICE)), Error::<Test, _>::AlreadyFeeder);
+			assert_noop!(Oracle::unbond_feeder(Origin::signed(ALICE), 0), Error::<Test, _>::NotBonded);
+
+			assert_ok!(Oracle::bond_feeder(Origin::signed(CHARLIE)));
+			System::assert_last_event(Event::Oracle(crate::Event::FeederBonded(CHARLIE, 100)));
+			assert_eq!(Oracle::feeders().to_vec(), vec![ALICE, CHARLIE]);
+			assert_eq!(Oracle::bonds(CHARLIE), Some(100));
+			assert_eq!(Balances::reserved_balance(CHARLIE), 100);
+
+			assert_ok!(Oracle::feed_values(Origin::signed(CHARLIE), vec![(1, 2)]));
+			assert!(PendingChecks::<Test>::contains_key(1, CHARLIE));
+			assert_noop!(Oracle::unbond_feeder(Origin::signed(CHARLIE), 0), Error::<Test, _>::TooManyKeys);
+			// charged for the values actually removed
+			assert_eq!(
+				Oracle::unbond_feeder(Origin::signed(CHARLIE), 10).unwrap().actual_weight,
+				Some(<() as WeightInfo>::unbond_feeder(2, 1))
+			);
+			System::assert_last_event(Event::Oracle(crate::Event::FeederUnbonded(CHARLIE, 100)));
+			assert_eq!(Oracle::feeders().to_vec(), vec![ALICE]);
+			assert_eq!(Oracle::bonds(CHARLIE), None);
+			assert_eq!(Oracle::all_value(CHARLIE, 1), None);
+			assert!(!PendingChecks::<Test>::contains_key(1, CHARLIE));
+			assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		})
+}
+
+#[test]
+fn remove_feeder_should_release_bond() {
+	ExtBuilder::default()
+		.balances(vec![(CHARLIE, 1000)])
+		.build_and_execute(|| {
+			assert_ok!(Oracle::bond_feeder(Origin::signed(CHARLIE)));
+			assert_ok!(Oracle::remove_feeder(Origin::root(), CHARLIE, 0));
+			assert_eq!(Oracle::bonds(CHARLIE), None);
+			assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		})
+}
+
+#[test]
+fn deviating_bonded_feeder_should_be_slashed() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE]
+		)
+		.balances(vec![(CHARLIE, 1000)])
+		.build_and_execute(|| {
+			let key: u32 = 50;
+
+			assert_ok!(Oracle::bond_feeder(Origin::signed(CHARLIE)));
+
+			// value within tolerance is not slashed
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1100)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(CHARLIE), vec![(key, 1050)]));
+			Oracle::on_finalize(1);
+			assert_eq!(Oracle::get(&key), Some(TimestampedValue {
+				value: 1050,
+				timestamp: 12345
+			}));
+			assert_eq!(Oracle::bonds(CHARLIE), Some(100));
+
+			// checked values are not slashed again
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1500)]));
+			Oracle::on_finalize(2);
+			assert_eq!(Oracle::get(&key), Some(TimestampedValue {
+				value: 1050,
+				timestamp: 12345
+			}));
+			assert_eq!(Oracle::bonds(CHARLIE), Some(100));
+
+			// slashed below the deposit, the feeder is unbonded
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1100)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(CHARLIE), vec![(key, 2000)]));
+			Oracle::on_finalize(3);
+			assert_eq!(Oracle::get(&key), Some(TimestampedValue {
+				value: 1100,
+				timestamp: 12345
+			}));
+			System::assert_has_event(Event::Oracle(crate::Event::FeederSlashed(CHARLIE, key, 50)));
+			System::assert_last_event(Event::Oracle(crate::Event::FeederUnbonded(CHARLIE, 50)));
+			assert_eq!(Oracle::bonds(CHARLIE), None);
+			assert_eq!(Oracle::feeders().to_vec(), vec![POLKAFOUNDRY, ALICE]);
+			assert_eq!(Oracle::get_concrete(&key, CHARLIE), None);
+			assert!(!Oracle::contributors(&key).contains(&CHARLIE));
+			assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+			assert_eq!(Balances::total_balance(&CHARLIE), 950);
+		})
+}
+
+#[test]
+fn slash_should_record_only_the_slashed_amount() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE]
+		)
+		.balances(vec![(CHARLIE, 1000)])
+		.build_and_execute(|| {
+			let key: u32 = 50;
+
+			assert_ok!(Oracle::bond_feeder(Origin::signed(CHARLIE)));
+			// most of the bond is already gone
+			let _ = <Balances as ReservableCurrency<AccountId>>::slash_reserved(&CHARLIE, 80);
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1100)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(CHARLIE), vec![(key, 2000)]));
+			Oracle::on_finalize(1);
+
+			System::assert_has_event(Event::Oracle(crate::Event::FeederSlashed(CHARLIE, key, 20)));
+			System::assert_last_event(Event::Oracle(crate::Event::FeederUnbonded(CHARLIE, 80)));
+			assert_eq!(Oracle::bonds(CHARLIE), None);
+			assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+			assert_eq!(Balances::total_balance(&CHARLIE), 900);
+		})
+}
+
+#[test]
+fn history_should_work() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB]
+		)
+		.build_and_execute(|| {
+			let key: u32 = 50;
+			assert_eq!(Oracle::history(&key), vec![]);
+
+			for (i, value) in [1000, 1100, 1200, 1300].iter().enumerate() {
+				let timestamp = 10000 + 100 * i as u32;
+				Timestamp::set_timestamp(timestamp);
+				assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, *value)]));
+				assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, *value)]));
+				assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, *value)]));
+				Oracle::on_finalize(i as u64);
+				assert_eq!(Oracle::get(&key), Some(TimestampedValue { value: *value, timestamp }));
+			}
+
+			assert_eq!(Oracle::history_count(&key), 4);
+			assert_eq!(
+				Oracle::history(&key),
+				vec![
+					TimestampedValue { value: 1100, timestamp: 10100 },
+					TimestampedValue { value: 1200, timestamp: 10200 },
+					TimestampedValue { value: 1300, timestamp: 10300 },
+				]
+			);
+			assert_eq!(
+				Oracle::history_between(&key, 10000, 10200),
+				vec![
+					TimestampedValue { value: 1100, timestamp: 10100 },
+					TimestampedValue { value: 1200, timestamp: 10200 },
+				]
+			);
+			assert_eq!(Oracle::history_between(&key, 10400, 10500), vec![]);
+		})
+}
+
+#[test]
+fn time_weighted_combine_data_should_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		type Twap = TimeWeightedCombineData<Test, MinimumCount, ExpiresIn, TwapWindow>;
+		let key: u32 = 50;
+		let fresh = |value, timestamp| vec![
+			TimestampedValue { value, timestamp },
+			TimestampedValue { value, timestamp },
+			TimestampedValue { value, timestamp },
+		];
+
+		Timestamp::set_timestamp(10250);
+		// no previous value
+		assert_eq!(
+			Twap::combine_data(&key, fresh(2000, 10250), None),
+			Some(TimestampedValue { value: 2000, timestamp: 10250 })
+		);
+		// not enough fresh values
+		assert_eq!(
+			Twap::combine_data(&key, vec![], Some(TimestampedValue { value: 1000, timestamp: 10000 })),
+			Some(TimestampedValue { value: 1000, timestamp: 10000 })
+		);
+		// a quarter of the window elapsed
+		assert_eq!(
+			Twap::combine_data(&key, fresh(2000, 10250), Some(TimestampedValue { value: 1000, timestamp: 10000 })),
+			Some(TimestampedValue { value: 1250, timestamp: 10250 })
+		);
+		assert_eq!(
+			Twap::combine_data(&key, fresh(600, 10250), Some(TimestampedValue { value: 1000, timestamp: 10000 })),
+			Some(TimestampedValue { value: 900, timestamp: 10250 })
+		);
+		// whole window elapsed
+		Timestamp::set_timestamp(11500);
+		assert_eq!(
+			Twap::combine_data(&key, fresh(2000, 11500), Some(TimestampedValue { value: 1000, timestamp: 10000 })),
+			Some(TimestampedValue { value: 2000, timestamp: 11500 })
+		);
+	});
+}
+
+#[test]
+fn fresh_data_provider_should_work() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB]
+		)
+		.build_and_execute(|| {
+			let key: u32 = 50;
+			assert_noop!(Oracle::get_fresh(&key, 600), Error::<Test, _>::NoValue);
+			assert_eq!(FreshDataProvider::<Test, MaxAge>::get(&key), None);
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1300)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			Oracle::on_finalize(1);
+
+			let expected = TimestampedValue { value: 1200, timestamp: 12345 };
+			Timestamp::set_timestamp(12345 + 600);
+			assert_eq!(Oracle::get_fresh(&key, 600), Ok(expected));
+			assert_eq!(FreshDataProvider::<Test, MaxAge>::get(&key), Some(expected));
+
+			// the previous value is still combined, but too old
+			Timestamp::set_timestamp(12345 + 601);
+			assert_eq!(Oracle::get(&key), Some(expected));
+			assert_noop!(Oracle::get_fresh(&key, 600), Error::<Test, _>::StaleValue);
+			assert_eq!(FreshDataProvider::<Test, MaxAge>::get(&key), None);
+		})
+}
+
+#[test]
+fn twap_data_provider_should_work() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB]
+		)
+		.build_and_execute(|| {
+			let key: u32 = 50;
+			assert_eq!(TwapDataProvider::<Test, TwapWindow>::get(&key), None);
+
+			for (i, (value, timestamp)) in [(1000, 10000), (2000, 10500)].iter().enumerate() {
+				Timestamp::set_timestamp(*timestamp);
+				assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, *value)]));
+				assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, *value)]));
+				assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, *value)]));
+				Oracle::on_finalize(i as u64);
+				assert!(Oracle::get(&key).is_some());
+			}
+
+			// the latest value has not been in effect for any time yet
+			assert_eq!(
+				TwapDataProvider::<Test, TwapWindow>::get(&key),
+				Some(TimestampedValue { value: 1000, timestamp: 10500 })
+			);
+
+			Timestamp::set_timestamp(11000);
+			assert_eq!(
+				TwapDataProvider::<Test, TwapWindow>::get(&key),
+				Some(TimestampedValue { value: 1500, timestamp: 10500 })
+			);
+
+			Timestamp::set_timestamp(12000);
+			assert_eq!(
+				TwapDataProvider::<Test, TwapWindow>::get(&key),
+				Some(TimestampedValue { value: 2000, timestamp: 10500 })
+			);
+		})
+}
+
+#[test]
+fn weighted_median_combine_data_should_work() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB, CHARLIE]
+		)
+		.build_and_execute(|| {
+			type WeightedMedian = WeightedMedianCombineData<Test, MinimumCount, ExpiresIn, FeederWeights>;
+			let key: u32 = 50;
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1200)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(CHARLIE), vec![(key, 5000)]));
+			// charlie has no weight, not enough feed
+			assert_eq!(WeightedMedian::combine_feeder_data(&key, Oracle::feeder_values(&key), None), None);
+
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1300)]));
+			let expected = Some(TimestampedValue {
+				value: 1000,
+				timestamp: 12345,
+			});
+			// charlie has no weight and does not contribute
+			assert_eq!(
+				WeightedMedian::combine_feeder_data(&key, Oracle::feeder_values(&key), None),
+				expected.map(|x| (x, vec![POLKAFOUNDRY, ALICE, BOB]))
+			);
+
+			Oracle::on_finalize(1);
+			Timestamp::set_timestamp(12500);
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 1400)]));
+			assert_eq!(
+				WeightedMedian::combine_feeder_data(&key, Oracle::feeder_values(&key), None),
+				Some((
+					TimestampedValue {
+						value: 1400,
+						timestamp: 12500,
+					},
+					vec![ALICE, BOB, POLKAFOUNDRY]
+				))
+			);
+
+			// only the given values are combined
+			assert_eq!(
+				WeightedMedian::combine_feeder_data(
+					&key,
+					vec![
+						(ALICE, TimestampedValue { value: 1200, timestamp: 12500 }),
+						(BOB, TimestampedValue { value: 1300, timestamp: 12500 }),
+						(CHARLIE, TimestampedValue { value: 900, timestamp: 12500 }),
+					],
+					None
+				),
+				None
+			);
+			assert_eq!(
+				WeightedMedian::combine_feeder_data(
+					&key,
+					vec![
+						(POLKAFOUNDRY, TimestampedValue { value: 900, timestamp: 12500 }),
+						(ALICE, TimestampedValue { value: 1200, timestamp: 12500 }),
+						(BOB, TimestampedValue { value: 1300, timestamp: 12500 }),
+					],
+					None
+				),
+				Some((
+					TimestampedValue {
+						value: 900,
+						timestamp: 12500,
+					},
+					vec![POLKAFOUNDRY, ALICE, BOB]
+				))
+			);
+
+			// values expired
+			Timestamp::set_timestamp(20000);
+			assert_eq!(
+				WeightedMedian::combine_feeder_data(&key, Oracle::feeder_values(&key), expected),
+				expected.map(|x| (x, Vec::new()))
+			);
+		})
+}
+
+#[test]
+fn trimmed_mean_combine_data_should_work() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB, CHARLIE]
+		)
+		.build_and_execute(|| {
+			type TrimmedMean = TrimmedMeanCombineData<Test, MinimumCount, ExpiresIn, TrimPercent>;
+			let key: u32 = 50;
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, 9000)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, 1000)]));
+			// not enough feed
+			assert_eq!(TrimmedMean::combine_feeder_data(&key, Oracle::feeder_values(&key), None), None);
+
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, 1200)]));
+			// nothing trimmed from three values
+			assert_eq!(
+				TrimmedMean::combine_feeder_data(&key, Oracle::feeder_values(&key), None),
+				Some((
+					TimestampedValue {
+						value: 3733,
+						timestamp: 12345,
+					},
+					vec![ALICE, BOB, POLKAFOUNDRY]
+				))
+			);
+
+			Timestamp::set_timestamp(12400);
+			assert_ok!(Oracle::feed_values(Origin::signed(CHARLIE), vec![(key, 100)]));
+			let expected = Some(TimestampedValue {
+				value: 1100,
+				timestamp: 12345,
+			});
+			// the trimmed values do not contribute
+			assert_eq!(
+				TrimmedMean::combine_feeder_data(&key, Oracle::feeder_values(&key), None),
+				expected.map(|x| (x, vec![ALICE, BOB]))
+			);
+
+			// values expired
+			Timestamp::set_timestamp(20000);
+			assert_eq!(
+				TrimmedMean::combine_feeder_data(&key, Oracle::feeder_values(&key), expected),
+				expected.map(|x| (x, Vec::new()))
+			);
+		})
+}
+
+#[test]
+fn deviating_combined_value_should_be_rejected() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB]
+		)
+		.build_and_execute(|| {
+			MaxDeviation::set(Permill::from_percent(20));
+			let key: u32 = 50;
+			let feed = |value| {
+				assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(key, value)]));
+				assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(key, value)]));
+				assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(key, value)]));
+			};
+
+			feed(1000);
+			Oracle::on_finalize(1);
+			let expected = Some(TimestampedValue {
+				value: 1000,
+				timestamp: 12345,
+			});
+			assert_eq!(Oracle::get(&key), expected);
+
+			Timestamp::set_timestamp(12400);
+			feed(1500);
+			Oracle::on_finalize(2);
+			assert_eq!(Oracle::get(&key), expected);
+			System::assert_last_event(Event::Oracle(crate::Event::CombinedValueRejected(
+				key,
+				TimestampedValue { value: 1000, timestamp: 12345 },
+				TimestampedValue { value: 1500, timestamp: 12400 },
+			)));
+			assert_eq!(Oracle::values(&key), expected);
+			assert_eq!(Oracle::history_count(&key), 1);
+			assert_eq!(Oracle::contributors(&key).to_vec(), Vec::<AccountId>::new());
+
+			feed(1100);
+			Oracle::on_finalize(3);
+			let expected2 = Some(TimestampedValue {
+				value: 1100,
+				timestamp: 12400,
+			});
+			assert_eq!(Oracle::get(&key), expected2);
+
+			feed(2000);
+			Oracle::on_finalize(4);
+			assert_eq!(Oracle::get(&key), expected2);
+			assert_noop!(Oracle::force_combine(Origin::signed(ALICE), key), BadOrigin);
+			assert_ok!(Oracle::force_combine(Origin::root(), key));
+			let forced = TimestampedValue {
+				value: 2000,
+				timestamp: 12400,
+			};
+			System::assert_last_event(Event::Oracle(crate::Event::CombinedValueForced(key, forced)));
+			assert_eq!(Oracle::get(&key), Some(forced));
+			assert_eq!(Oracle::history_count(&key), 3);
+		})
+}
+
+#[test]
+fn feeders_and_feed_values_should_be_bounded() {
+	ExtBuilder::default()
+		.feeders(
+			vec![POLKAFOUNDRY, ALICE, BOB, CHARLIE]
+		)
+		.balances(vec![(6, 1000)])
+		.build_and_execute(|| {
+			assert_ok!(Oracle::elect_feeder(Origin::root(), 5));
+			assert_noop!(Oracle::elect_feeder(Origin::root(), 6), Error::<Test, _>::TooManyFeeders);
+			assert_noop!(Oracle::bond_feeder(Origin::signed(6)), Error::<Test, _>::TooManyFeeders);
+
+			let values: Vec<(Key, Value)> = (0..11).map(|i| (i, 100)).collect();
+			assert_noop!(
+				Oracle::feed_values(Origin::signed(ALICE), values.clone()),
+				Error::<Test, _>::TooManyFeedValues
+			);
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), values[..10].to_vec()));
+		})
+}
+
+#[test]
+fn migrate_to_bounded_feeders_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(7, 1000)])
+		.build_and_execute(|| {
+			assert_ok!(Oracle::bond_feeder(Origin::signed(7)));
+			assert_ok!(Oracle::feed_values(Origin::signed(7), vec![(1, 100)]));
+
+			let feeders: Vec<AccountId> = vec![7, 6, 5, 4, 3, 2, 1];
+			frame_support::storage::unhashed::put(&Feeders::<Test>::hashed_key(), &feeders);
+			StorageVersion::<Test>::kill();
+			assert!(PendingChecks::<Test>::contains_key(1, 7));
+			migration::put_storage_value(b"Oracle", b"HasFeeded", &7u64.encode(), true);
+			migration::put_storage_value(b"Oracle", b"IsCombined", &1u32.encode(), true);
+
+			assert!(migrations::migrate_to_bounded_feeders::<Test, ()>() > 0);
+			assert_eq!(migration::get_storage_value::<bool>(b"Oracle", b"HasFeeded", &7u64.encode()), None);
+			assert_eq!(migration::get_storage_value::<bool>(b"Oracle", b"IsCombined", &1u32.encode()), None);
+			assert_eq!(Oracle::feeders().to_vec(), vec![1, 2, 3, 4, 5]);
+			assert_eq!(StorageVersion::<Test>::get(), migrations::Releases::V2);
+			assert_eq!(Oracle::all_value(7, 1), None);
+			assert!(!PendingChecks::<Test>::contains_key(1, 7));
+			assert_eq!(Oracle::bonds(7), None);
+			assert_eq!(Balances::reserved_balance(7), 0);
+
+			// runs only once
+			AllValue::<Test>::insert(7, 1, TimestampedValue { value: 100, timestamp: 12345 });
+			assert_eq!(migrations::migrate_to_bounded_feeders::<Test, ()>(), 0);
+			assert!(Oracle::all_value(7, 1).is_some());
+		})
+}
+
+#[test]
+fn change_members_should_follow_membership() {
+	ExtBuilder::default()
+		.feeders(vec![POLKAFOUNDRY, ALICE])
+		.balances(vec![(BOB, 1000)])
+		.build_and_execute(|| {
+			assert_ok!(Oracle::bond_feeder(Origin::signed(BOB)));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(1, 100)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(1, 100)]));
+
+			System::reset_events();
+			<Oracle as ChangeMembers<AccountId>>::change_members_sorted(&[CHARLIE], &[ALICE, BOB, 9], &[CHARLIE]);
+
+			assert_eq!(Oracle::feeders().to_vec(), vec![POLKAFOUNDRY, CHARLIE]);
+			assert_eq!(Oracle::get_concrete(&1, ALICE), None);
+			assert_eq!(Oracle::get_concrete(&1, BOB), None);
+			assert!(!PendingChecks::<Test>::contains_key(1, BOB));
+			assert_eq!(Oracle::bonds(BOB), None);
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			assert_eq!(
+				*System::block_weight().get(DispatchClass::Mandatory),
+				<() as WeightInfo>::remove_feeder(3, 1) + <() as WeightInfo>::remove_feeder(2, 1)
+			);
+			System::assert_has_event(Event::Oracle(crate::Event::RemoveFeeder(ALICE)));
+			System::assert_has_event(Event::Oracle(crate::Event::RemoveFeeder(BOB)));
+			// only feeders are removed
+			assert!(!System::events()
+				.iter()
+				.any(|record| record.event == Event::Oracle(crate::Event::RemoveFeeder(9))));
+			System::assert_last_event(Event::Oracle(crate::Event::NewFeederElected(CHARLIE)));
+
+			// members beyond `MaxFeeders` are not elected
+			<Oracle as ChangeMembers<AccountId>>::change_members_sorted(&[5, 6, 7, 8], &[], &[CHARLIE, 5, 6, 7, 8]);
+			assert_eq!(Oracle::feeders().to_vec(), vec![POLKAFOUNDRY, CHARLIE, 5, 6, 7]);
+			System::assert_last_event(Event::Oracle(crate::Event::FeederNotElected(8)));
+		});
+}
+
+#[test]
+fn initialize_members_should_elect_feeders() {
+	ExtBuilder::default()
+		.feeders(vec![POLKAFOUNDRY])
+		.build_and_execute(|| {
+			<Oracle as InitializeMembers<AccountId>>::initialize_members(&[ALICE, BOB]);
+			assert_eq!(Oracle::feeders().to_vec(), vec![POLKAFOUNDRY, ALICE, BOB]);
+		});
+}
+
+fn set_sources(sources: Vec<(Key, PriceSource)>) {
+	StorageValueRef::persistent(&offchain::sources_key::<Test, ()>()).set(&sources);
+}
+
+fn source(url: &str, path: &str, decimals: u32) -> PriceSource {
+	PriceSource {
+		url: url.as_bytes().to_vec(),
+		path: path.as_bytes().to_vec(),
+		decimals,
+	}
+}
+
+#[test]
+fn offchain_worker_should_feed_fetched_values() {
+	let (offchain, state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().feeders(vec![ALICE]).build();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	state.write().expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: "http://localhost/btc".into(),
+		response: Some(br#"{"bitcoin":{"usd":123.45}}"#.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+	state.write().expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: "http://localhost/dot".into(),
+		response: Some(br#"{"data":[{"price":1},{"price":1.5e-1}]}"#.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+
+	ext.execute_with(|| {
+		// BOB is not a feeder, the key of ALICE is used
+		UintAuthorityId::set_all_keys(vec![BOB, ALICE]);
+		set_sources(vec![
+			(1, source("http://localhost/btc", "bitcoin.usd", 2)),
+			(2, source("http://localhost/dot", "data.1.price", 4)),
+		]);
+
+		Oracle::offchain_worker(1);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, Some((ALICE, ())));
+		assert_eq!(
+			tx.call,
+			mock::Call::Oracle(crate::Call::feed_values(vec![(1, 12345), (2, 1500)]))
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_should_not_feed_without_feeder_key_or_sources() {
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().feeders(vec![ALICE]).build();
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		UintAuthorityId::set_all_keys(vec![ALICE]);
+		assert_eq!(HttpFeeder::<Test, TestAuthId>::fetch_and_feed(), Err(OffchainErr::NoSources));
+
+		set_sources(vec![(1, source("http://localhost/btc", "bitcoin.usd", 2))]);
+		UintAuthorityId::set_all_keys(vec![BOB]);
+		assert_eq!(HttpFeeder::<Test, TestAuthId>::fetch_and_feed(), Err(OffchainErr::NoFeederKey));
+
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_should_skip_failed_sources() {
+	let (offchain, state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().feeders(vec![ALICE]).build();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	state.write().expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: "http://localhost/btc".into(),
+		response: Some(br#"{"bitcoin":{"usd":123.45}}"#.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+	state.write().expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: "http://localhost/dot".into(),
+		response: Some(br#"{"data":[]}"#.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+
+	ext.execute_with(|| {
+		UintAuthorityId::set_all_keys(vec![ALICE]);
+		set_sources(vec![
+			(1, source("http://localhost/btc", "bitcoin.usd", 2)),
+			(2, source("http://localhost/dot", "data.1.price", 4)),
+		]);
+
+		assert_eq!(HttpFeeder::<Test, TestAuthId>::fetch_and_feed(), Ok(()));
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			mock::Call::Oracle(crate::Call::feed_values(vec![(1, 12345)]))
+		);
+
+		// nothing is fed if every source fails
+		set_sources(vec![(2, source("http://localhost/dot", "data.1.price", 4))]);
+		state.write().expect_request(PendingRequest {
+			method: "GET".into(),
+			uri: "http://localhost/dot".into(),
+			response: Some(br#"{"data":[]}"#.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+		assert_eq!(HttpFeeder::<Test, TestAuthId>::fetch_and_feed(), Err(OffchainErr::Json));
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+orml_traits::create_median_value_data_provider!(
+	AggregatedOracle,
+	Key,
+	AccountId,
+	TimestampedValue<Value, u32>,
+	TimestampedValue<Value, u32>,
+	[Oracle, OracleB]
+);
+
+#[test]
+fn median_value_data_provider_should_aggregate_instances() {
+	ExtBuilder::default()
+		.feeders(vec![POLKAFOUNDRY, ALICE, BOB])
+		.build_and_execute(|| {
+			let value = |value| Some(TimestampedValue { value, timestamp: 12345 });
+			for feeder in vec![ALICE, BOB, CHARLIE] {
+				assert_ok!(OracleB::elect_feeder(Origin::root(), feeder));
+			}
+
+			assert_ok!(Oracle::feed_values(Origin::signed(POLKAFOUNDRY), vec![(1, 1000), (2, 500)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(ALICE), vec![(1, 1100), (2, 500)]));
+			assert_ok!(Oracle::feed_values(Origin::signed(BOB), vec![(1, 1200), (2, 500)]));
+			assert_ok!(OracleB::feed_values(Origin::signed(ALICE), vec![(1, 2000)]));
+			assert_ok!(OracleB::feed_values(Origin::signed(BOB), vec![(1, 2100)]));
+			assert_ok!(OracleB::feed_values(Origin::signed(CHARLIE), vec![(1, 2200)]));
+			Oracle::on_finalize(1);
+			OracleB::on_finalize(1);
+
+			assert_eq!(<AggregatedOracle as DataProvider<_, _>>::get(&1), value(2100));
+			assert_eq!(<AggregatedOracle as DataProvider<_, _>>::get(&2), value(500));
+			assert_eq!(<AggregatedOracle as DataProvider<_, _>>::get(&3), None);
+
+			assert_eq!(
+				<AggregatedOracle as DataProviderExtended<_, _, _>>::get_concrete(&1, ALICE),
+				value(2000)
+			);
+			assert_eq!(
+				<AggregatedOracle as DataProviderExtended<_, _, _>>::get_concrete(&1, POLKAFOUNDRY),
+				value(1000)
+			);
+			assert_eq!(
+				<AggregatedOracle as DataProviderExtended<_, _, _>>::get_polkafoundry(&1),
+				value(2200)
+			);
+			assert_eq!(
+				<AggregatedOracle as DataProviderExtended<_, _, _>>::get_all_values(),
+				vec![(1, value(2100)), (2, value(500))]
+			);
+		});
+}
+
+#[test]
+fn feed_values_unsigned_should_work() {
+	ExtBuilder::default()
+		.alice_is_feeder()
+		.build_and_execute(|| {
+			let payload = |feeder, nonce, block_number| FeedPayload {
+				feeder,
+				values: vec![(1, 100)],
+				block_number,
+				nonce,
+			};
+			let validate = |payload: FeedPayloadOf<Test>, signer| {
+				let signature = TestSignature(signer, Oracle::signing_payload(&payload));
+				let call = crate::Call::<Test>::feed_values_unsigned(payload, signature);
+				<Oracle as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call)
+			};
+
+			assert_eq!(
+				validate(payload(ALICE, 0, 1), ALICE),
+				ValidTransaction::with_tag_prefix("OracleFeedPayload")
+					.priority(UnsignedPriority::get())
+					.and_provides((ALICE, 0u32))
+					.longevity(5)
+					.propagate(true)
+					.build()
+			);
+			assert_eq!(validate(payload(ALICE, 0, 1), BOB), Err(InvalidTransaction::BadProof.into()));
+			assert_eq!(validate(payload(BOB, 0, 1), BOB), Err(InvalidTransaction::BadProof.into()));
+			assert_eq!(validate(payload(ALICE, 0, 2), ALICE), Err(InvalidTransaction::Future.into()));
+			// the unsigned payload bytes are not accepted
+			let call = crate::Call::<Test>::feed_values_unsigned(
+				payload(ALICE, 0, 1),
+				TestSignature(ALICE, payload(ALICE, 0, 1).encode()),
+			);
+			assert_eq!(
+				<Oracle as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+				Err(InvalidTransaction::BadProof.into())
+			);
+
+			// future payloads wait for the previous nonce
+			assert_eq!(
+				validate(payload(ALICE, 1, 1), ALICE),
+				ValidTransaction::with_tag_prefix("OracleFeedPayload")
+					.priority(UnsignedPriority::get())
+					.and_provides((ALICE, 1u32))
+					.longevity(5)
+					.propagate(true)
+					.and_requires((ALICE, 0u32))
+					.build()
+			);
+			let signature = TestSignature(ALICE, Oracle::signing_payload(&payload(ALICE, 1, 1)));
+			assert_noop!(
+				Oracle::feed_values_unsigned(Origin::none(), payload(ALICE, 1, 1), signature),
+				Error::<Test, _>::InvalidNonce
+			);
+
+			let signature = TestSignature(ALICE, Oracle::signing_payload(&payload(ALICE, 0, 1)));
+			assert_noop!(
+				Oracle::feed_values_unsigned(Origin::signed(ALICE), payload(ALICE, 0, 1), signature.clone()),
+				BadOrigin
+			);
+			assert_ok!(Oracle::feed_values_unsigned(Origin::none(), payload(ALICE, 0, 1), signature.clone()));
+			assert_eq!(
+				Oracle::all_value(ALICE, 1),
+				Some(TimestampedValue { value: 100, timestamp: 12345 })
+			);
+			assert_eq!(Oracle::feed_nonces(ALICE), 1);
+
+			// replayed payloads are rejected
+			assert_eq!(validate(payload(ALICE, 0, 1), ALICE), Err(InvalidTransaction::Stale.into()));
+			assert_noop!(
+				Oracle::feed_values_unsigned(Origin::none(), payload(ALICE, 0, 1), signature),
+				Error::<Test, _>::InvalidNonce
+			);
+
+			// expired payloads are rejected
+			System::set_block_number(6);
+			assert_eq!(validate(payload(ALICE, 1, 1), ALICE), Err(InvalidTransaction::Stale.into()));
+			assert_ok!(validate(payload(ALICE, 1, 2), ALICE));
+		})
+}
+
+#[test]
+fn feed_values_unsigned_should_not_replay_on_other_instances() {
+	ExtBuilder::default()
+		.alice_is_feeder()
+		.build_and_execute(|| {
+			assert_ok!(OracleB::elect_feeder(Origin::root(), ALICE));
+			let payload = FeedPayload {
+				feeder: ALICE,
+				values: vec![(1, 100)],
+				block_number: 1,
+				nonce: 0,
+			};
+			assert_ne!(Oracle::signing_payload(&payload), OracleB::signing_payload(&payload));
+
+			let signature = TestSignature(ALICE, Oracle::signing_payload(&payload));
+			assert_noop!(
+				OracleB::feed_values_unsigned(Origin::none(), payload.clone(), signature.clone()),
+				Error::<Test, Instance1>::InvalidSignature
+			);
+			assert_ok!(Oracle::feed_values_unsigned(Origin::none(), payload, signature));
+		})
+}
@@ -0,0 +1,111 @@
+//! Weights for pkfp_oracle
+//!
+//! Default weights below are conservative estimates and should be regenerated
+//! on reference hardware with the benchmark CLI.
+
+// Command to regenerate:
+// ./target/release/polkafoundry
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pkfp_oracle
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./oracle/src/weights.rs
+// --template
+// ../templates/orml-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pkfp_oracle.
+pub trait WeightInfo {
+	fn elect_feeder(f: u32, ) -> Weight;
+	fn remove_feeder(f: u32, k: u32, ) -> Weight;
+	fn feed_values(k: u32, f: u32, ) -> Weight;
+	fn combine(f: u32, ) -> Weight;
+	fn set_fee(f: u32, ) -> Weight;
+	fn read_value(f: u32, ) -> Weight;
+	fn force_combine(f: u32, ) -> Weight;
+	fn bond_feeder(f: u32, ) -> Weight;
+	fn unbond_feeder(f: u32, k: u32, ) -> Weight;
+}
+
+/// Default weights.
+impl WeightInfo for () {
+	fn elect_feeder(f: u32, ) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((120_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_feeder(f: u32, k: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((120_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add((1_800_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(k as Weight)))
+	}
+	fn feed_values(k: u32, f: u32, ) -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add((4_500_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add((110_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(k as Weight)))
+	}
+	fn combine(f: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((6_200_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(f as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(f as Weight)))
+	}
+	fn set_fee(f: u32, ) -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn read_value(f: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((52_000_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(f as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(f as Weight)))
+	}
+	fn force_combine(f: u32, ) -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add((6_200_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(f as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(f as Weight)))
+	}
+	fn bond_feeder(f: u32, ) -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add((120_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn unbond_feeder(f: u32, k: u32, ) -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add((120_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add((1_800_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(k as Weight)))
+	}
+}
@@ -21,5 +21,27 @@ pub enum DataProviderId {
 	All = 3,
 }
 
+/// Feeder of an oracle with its fee for paid reads.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FeederInfo<AccountId, Balance> {
+	pub feeder: AccountId,
+	pub fee: Balance,
+	/// Whether the feeder already fed in the current block
+	pub has_feeded: bool,
+}
+
+/// Freshness of the combined value of an oracle key.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OracleKeyInfo<Moment> {
+	/// Timestamp of the combined value
+	pub last_update: Option<Moment>,
+	/// Number of feeders with a value that has not expired
+	pub fresh_feeders: u32,
+	/// Number of fresh values required to combine a new value
+	pub minimum_count: u32,
+}
+
 pub type Amount = i128;
 pub type Price = FixedU128;
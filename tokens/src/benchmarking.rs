@@ -0,0 +1,238 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Tokens;
+
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+pub use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use sp_runtime::traits::One;
+
+const SEED: u32 = 0;
+/// Currency the benchmarks run against. Its existential deposit should be
+/// at least 2 for the dust removal path to be measured.
+const CURRENCY_ID: u32 = 1;
+
+fn currency_id<T: Config>() -> T::CurrencyId
+where
+	T::CurrencyId: From<u32>,
+{
+	CURRENCY_ID.into()
+}
+
+/// An amount large enough to create the destination account.
+fn transfer_amount<T: Config>(currency_id: T::CurrencyId) -> T::Balance {
+	T::ExistentialDeposits::get(&currency_id).saturating_add(1_000u32.into())
+}
+
+/// Make `who` the issuer and admin of `currency_id`.
+fn set_roles<T: Config>(currency_id: T::CurrencyId, who: &T::AccountId) {
+	Roles::<T>::insert(
+		currency_id,
+		CurrencyRoles {
+			issuer: who.clone(),
+			admin: who.clone(),
+		},
+	);
+}
+
+fn lock_id(i: u32) -> LockIdentifier {
+	let mut id = *b"lock    ";
+	id[4..].copy_from_slice(&i.to_be_bytes());
+	id
+}
+
+fn set_locks<T: Config>(currency_id: T::CurrencyId, who: &T::AccountId, l: u32, amount: T::Balance) {
+	for i in 0..l {
+		assert_ok!(<Tokens<T> as MultiLockableCurrency<_>>::set_lock(
+			lock_id(i),
+			currency_id,
+			who,
+			amount
+		));
+	}
+}
+
+benchmarks! {
+	where_clause { where T::CurrencyId: From<u32> }
+
+	// the destination is created and the remainder of the sender, held by
+	// `MaxLocks` locks, is removed as dust
+	transfer {
+		let currency_id = currency_id::<T>();
+		let amount = transfer_amount::<T>(currency_id);
+		let dust = T::ExistentialDeposits::get(&currency_id).saturating_sub(One::one()).max(One::one());
+		let from: T::AccountId = whitelisted_caller();
+		assert_ok!(<Tokens<T> as MultiCurrency<_>>::deposit(currency_id, &from, amount.saturating_add(dust)));
+		set_locks::<T>(currency_id, &from, T::MaxLocks::get(), One::one());
+
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+	}: _(RawOrigin::Signed(from.clone()), to_lookup, currency_id, amount)
+	verify {
+		assert_eq!(<Tokens<T> as MultiCurrency<_>>::free_balance(currency_id, &to), amount);
+	}
+
+	// the destination is created and the sender keeps the balance of its
+	// `MaxLocks` locks
+	transfer_all {
+		let currency_id = currency_id::<T>();
+		let amount = transfer_amount::<T>(currency_id);
+		let locked = T::ExistentialDeposits::get(&currency_id).max(One::one());
+		let from: T::AccountId = whitelisted_caller();
+		assert_ok!(<Tokens<T> as MultiCurrency<_>>::deposit(currency_id, &from, amount.saturating_add(locked)));
+		set_locks::<T>(currency_id, &from, T::MaxLocks::get(), locked);
+
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+	}: _(RawOrigin::Signed(from.clone()), to_lookup, currency_id)
+	verify {
+		assert_eq!(<Tokens<T> as MultiCurrency<_>>::free_balance(currency_id, &from), locked);
+		assert_eq!(<Tokens<T> as MultiCurrency<_>>::free_balance(currency_id, &to), amount);
+	}
+
+	// a new lock is added next to `l` existing ones
+	set_lock {
+		let l in 0 .. T::MaxLocks::get().saturating_sub(1);
+		let currency_id = currency_id::<T>();
+		let amount = transfer_amount::<T>(currency_id);
+		let who: T::AccountId = account("who", 0, SEED);
+		assert_ok!(<Tokens<T> as MultiCurrency<_>>::deposit(currency_id, &who, amount));
+		set_locks::<T>(currency_id, &who, l, amount);
+	}: {
+		assert_ok!(<Tokens<T> as MultiLockableCurrency<_>>::set_lock(lock_id(l), currency_id, &who, amount));
+	}
+	verify {
+		assert_eq!(Locks::<T>::get(&who, currency_id).len(), l as usize + 1);
+	}
+
+	// the last of `l` locks is removed
+	remove_lock {
+		let l in 1 .. T::MaxLocks::get();
+		let currency_id = currency_id::<T>();
+		let amount = transfer_amount::<T>(currency_id);
+		let who: T::AccountId = account("who", 0, SEED);
+		assert_ok!(<Tokens<T> as MultiCurrency<_>>::deposit(currency_id, &who, amount));
+		set_locks::<T>(currency_id, &who, l, amount);
+	}: {
+		assert_ok!(<Tokens<T> as MultiLockableCurrency<_>>::remove_lock(lock_id(l - 1), currency_id, &who));
+	}
+	verify {
+		assert_eq!(Locks::<T>::get(&who, currency_id).len(), l as usize - 1);
+	}
+
+	set_pause_status {
+		let currency_id = currency_id::<T>();
+		let status = PauseStatus { transfer: true, deposit: true, withdraw: true };
+		let origin = T::PauseOrigin::successful_origin();
+	}: _<T::Origin>(origin, currency_id, status)
+	verify {
+		assert_eq!(Tokens::<T>::pause_status(currency_id), status);
+	}
+
+	freeze {
+		let currency_id = currency_id::<T>();
+		let who: T::AccountId = account("who", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		let origin = T::FreezeOrigin::successful_origin();
+	}: _<T::Origin>(origin, who_lookup, currency_id)
+	verify {
+		assert!(Tokens::<T>::is_frozen(&who, currency_id));
+	}
+
+	thaw {
+		let currency_id = currency_id::<T>();
+		let who: T::AccountId = account("who", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		FrozenAccounts::<T>::insert(&who, currency_id, true);
+		let origin = T::FreezeOrigin::successful_origin();
+	}: _<T::Origin>(origin, who_lookup, currency_id)
+	verify {
+		assert!(!Tokens::<T>::is_frozen(&who, currency_id));
+	}
+
+	// registers a new currency with the longest name and symbol
+	set_currency_metadata {
+		let currency_id = currency_id::<T>();
+		let name = vec![b'n'; T::StringLimit::get() as usize];
+		let symbol = vec![b's'; T::StringLimit::get() as usize];
+		let origin = T::RegisterOrigin::successful_origin();
+	}: _<T::Origin>(origin, currency_id, name, symbol, 12, 1u32.into())
+	verify {
+		assert!(Tokens::<T>::currency_metadata(currency_id).is_some());
+	}
+
+	set_currency_status {
+		let currency_id = currency_id::<T>();
+		assert_ok!(Tokens::<T>::do_set_currency_metadata(currency_id, vec![], vec![], 12, 1u32.into()));
+		let origin = T::RegisterOrigin::successful_origin();
+	}: _<T::Origin>(origin, currency_id, CurrencyStatus::Deprecated)
+	verify {
+		assert_eq!(
+			Tokens::<T>::currency_metadata(currency_id).map(|metadata| metadata.status),
+			Some(CurrencyStatus::Deprecated)
+		);
+	}
+
+	set_issuer {
+		let currency_id = currency_id::<T>();
+		let issuer: T::AccountId = account("issuer", 0, SEED);
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let issuer_lookup = T::Lookup::unlookup(issuer.clone());
+		let admin_lookup = T::Lookup::unlookup(admin.clone());
+		let origin = T::RegisterOrigin::successful_origin();
+	}: _<T::Origin>(origin, currency_id, issuer_lookup, admin_lookup)
+	verify {
+		assert_eq!(Tokens::<T>::roles(currency_id), Some(CurrencyRoles { issuer, admin }));
+	}
+
+	// the destination is created
+	mint {
+		let currency_id = currency_id::<T>();
+		let amount = transfer_amount::<T>(currency_id);
+		let issuer: T::AccountId = whitelisted_caller();
+		set_roles::<T>(currency_id, &issuer);
+
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = T::Lookup::unlookup(dest.clone());
+	}: _(RawOrigin::Signed(issuer), dest_lookup, currency_id, amount)
+	verify {
+		assert_eq!(<Tokens<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+	}
+
+	// the remainder of the burned account is removed as dust
+	burn {
+		let currency_id = currency_id::<T>();
+		let amount = transfer_amount::<T>(currency_id);
+		let dust = T::ExistentialDeposits::get(&currency_id).saturating_sub(One::one());
+		let admin: T::AccountId = whitelisted_caller();
+		set_roles::<T>(currency_id, &admin);
+
+		let who: T::AccountId = account("who", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		assert_ok!(<Tokens<T> as MultiCurrency<_>>::deposit(currency_id, &who, amount.saturating_add(dust)));
+	}: _(RawOrigin::Signed(admin), who_lookup, currency_id, amount)
+	verify {
+		assert!(<Tokens<T> as MultiCurrency<_>>::free_balance(currency_id, &who) <= dust);
+	}
+
+	// the destination is created and the remainder of the source is removed as dust
+	force_transfer {
+		let currency_id = currency_id::<T>();
+		let amount = transfer_amount::<T>(currency_id);
+		let dust = T::ExistentialDeposits::get(&currency_id).saturating_sub(One::one());
+		let admin: T::AccountId = whitelisted_caller();
+		set_roles::<T>(currency_id, &admin);
+
+		let source: T::AccountId = account("source", 0, SEED);
+		let source_lookup = T::Lookup::unlookup(source.clone());
+		assert_ok!(<Tokens<T> as MultiCurrency<_>>::deposit(currency_id, &source, amount.saturating_add(dust)));
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = T::Lookup::unlookup(dest.clone());
+	}: _(RawOrigin::Signed(admin), source_lookup, dest_lookup, currency_id, amount)
+	verify {
+		assert_eq!(<Tokens<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+	}
+}
+
+impl_benchmark_test_suite!(Tokens, crate::mock::ExtBuilder::default().build(), crate::mock::Runtime);
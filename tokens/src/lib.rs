@@ -28,11 +28,22 @@
 //!
 //! - `transfer` - Transfer some balance to another account.
 //! - `transfer_all` - Transfer all balance to another account.
+//! - `set_pause_status` - Pause or resume transfers, deposits and withdrawals
+//!   of a currency.
+//! - `freeze` - Freeze the free balance of an account in a currency.
+//! - `thaw` - Allow the free balance of a frozen account to move again.
+//! - `set_currency_metadata` - Register a currency or update its metadata.
+//! - `set_currency_status` - Set the status of a registered currency.
+//! - `set_issuer` - Set the issuer and admin of a currency.
+//! - `mint` - Issue new balance of a currency, by its issuer.
+//! - `burn` - Destroy balance of a currency, by its admin.
+//! - `force_transfer` - Transfer balance between accounts, by the admin of the
+//!   currency.
 //!
 //! ### Genesis Config
 //!
-//! The tokens module depends on the `GenesisConfig`. Endowed accounts could be
-//! configured in genesis configs.
+//! The tokens module depends on the `GenesisConfig`. Endowed accounts and
+//! registered currencies could be configured in genesis configs.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -71,6 +82,8 @@ use sp_std::{
 	vec::Vec,
 };
 
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
 mod imbalances;
 mod mock;
 mod tests;
@@ -91,6 +104,23 @@ where
 	}
 }
 
+/// Existential deposits of the registered currencies, falling back to
+/// `Fallback` for unregistered ones.
+///
+/// Use as `Config::ExistentialDeposits` in place of constants.
+pub struct RegisteredExistentialDeposits<T, Fallback>(marker::PhantomData<(T, Fallback)>);
+impl<T, Fallback> GetByKey<T::CurrencyId, T::Balance> for RegisteredExistentialDeposits<T, Fallback>
+where
+	T: Config,
+	Fallback: GetByKey<T::CurrencyId, T::Balance>,
+{
+	fn get(currency_id: &T::CurrencyId) -> T::Balance {
+		Pallet::<T>::currency_metadata(currency_id)
+			.map(|metadata| metadata.existential_deposit)
+			.unwrap_or_else(|| Fallback::get(currency_id))
+	}
+}
+
 pub struct BurnDust<T>(marker::PhantomData<T>);
 impl<T: Config> OnDust<T::AccountId, T::CurrencyId, T::Balance> for BurnDust<T> {
 	fn on_dust(who: &T::AccountId, currency_id: T::CurrencyId, amount: T::Balance) {
@@ -147,6 +177,57 @@ impl<Balance: Saturating + Copy + Ord> AccountData<Balance> {
 	}
 }
 
+/// Operations of a currency that are paused.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, MaxEncodedLen, RuntimeDebug)]
+pub struct PauseStatus {
+	/// Transfers between accounts are rejected.
+	pub transfer: bool,
+	/// Deposits into accounts, increasing the issuance, are rejected.
+	pub deposit: bool,
+	/// Withdrawals from accounts, decreasing the issuance, are rejected.
+	pub withdraw: bool,
+}
+
+impl PauseStatus {
+	/// Whether any operation is paused.
+	pub fn is_paused(&self) -> bool {
+		self.transfer || self.deposit || self.withdraw
+	}
+}
+
+/// Status of a registered currency.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum CurrencyStatus {
+	/// The currency is in use.
+	Active,
+	/// The currency is phased out and should no longer be offered by clients.
+	Deprecated,
+}
+
+/// Metadata of a registered currency.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CurrencyMetadata<Balance, BoundedString> {
+	/// The user friendly name of the currency.
+	pub name: BoundedString,
+	/// The ticker symbol of the currency.
+	pub symbol: BoundedString,
+	/// The number of decimals of the currency.
+	pub decimals: u8,
+	/// The minimum amount required to keep an account.
+	pub existential_deposit: Balance,
+	/// The status of the currency.
+	pub status: CurrencyStatus,
+}
+
+/// Accounts holding the roles of a currency.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CurrencyRoles<AccountId> {
+	/// May mint new balance.
+	pub issuer: AccountId,
+	/// May burn balance, force transfers and change the roles.
+	pub admin: AccountId,
+}
+
 pub use module::*;
 
 #[frame_support::pallet]
@@ -190,6 +271,19 @@ pub mod module {
 		type OnDust: OnDust<Self::AccountId, Self::CurrencyId, Self::Balance>;
 
 		type MaxLocks: Get<u32>;
+
+		/// The origin which may pause and resume the operations of a
+		/// currency.
+		type PauseOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may freeze and thaw accounts.
+		type FreezeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may register currencies and set their metadata.
+		type RegisterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum length of the name and symbol of a currency.
+		type StringLimit: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -206,6 +300,20 @@ pub mod module {
 		KeepAlive,
 		/// Value too low to create account due to existential deposit
 		ExistentialDeposit,
+		/// Transfers of the currency are paused
+		TransferPaused,
+		/// Deposits of the currency are paused
+		DepositPaused,
+		/// Withdrawals of the currency are paused
+		WithdrawPaused,
+		/// The free balance of the account is frozen
+		AccountFrozen,
+		/// The name or symbol of the currency is too long
+		BadMetadata,
+		/// The currency is not registered
+		CurrencyNotRegistered,
+		/// The origin doesn't hold the required role of the currency
+		NoPermission,
 	}
 
 	#[pallet::event]
@@ -226,6 +334,30 @@ pub mod module {
 		/// Some balance was unreserved (moved from reserved to free).
 		/// \[currency_id, who, value\]
 		Unreserved(T::CurrencyId, T::AccountId, T::Balance),
+		/// The paused operations of a currency changed. \[currency_id,
+		/// status\]
+		PauseStatusChanged(T::CurrencyId, PauseStatus),
+		/// The free balance of an account was frozen. \[currency_id, who\]
+		Frozen(T::CurrencyId, T::AccountId),
+		/// The free balance of an account was thawed. \[currency_id, who\]
+		Thawed(T::CurrencyId, T::AccountId),
+		/// A currency was registered. \[currency_id\]
+		CurrencyRegistered(T::CurrencyId),
+		/// The metadata of a registered currency was updated.
+		/// \[currency_id\]
+		CurrencyMetadataUpdated(T::CurrencyId),
+		/// The status of a registered currency changed. \[currency_id,
+		/// status\]
+		CurrencyStatusChanged(T::CurrencyId, CurrencyStatus),
+		/// The issuer and admin of a currency were set. \[currency_id,
+		/// issuer, admin\]
+		IssuerSet(T::CurrencyId, T::AccountId, T::AccountId),
+		/// Some balance was minted by the issuer. \[currency_id, who,
+		/// amount\]
+		Minted(T::CurrencyId, T::AccountId, T::Balance),
+		/// Some balance was burned by the admin. \[currency_id, who,
+		/// amount\]
+		Burned(T::CurrencyId, T::AccountId, T::Balance),
 	}
 
 	/// The total issuance of a token type.
@@ -265,21 +397,73 @@ pub mod module {
 		ValueQuery,
 	>;
 
+	/// The paused operations of a token type.
+	///
+	/// NOTE: Currencies without paused operations have no entry.
+	#[pallet::storage]
+	#[pallet::getter(fn pause_status)]
+	pub type CurrencyPauseStatus<T: Config> = StorageMap<_, Twox64Concat, T::CurrencyId, PauseStatus, ValueQuery>;
+
+	/// Accounts whose free balance of a token type is frozen by
+	/// `FreezeOrigin`, independently of their locks.
+	///
+	/// NOTE: Reserved balance and slashing are not affected.
+	#[pallet::storage]
+	#[pallet::getter(fn is_frozen)]
+	pub type FrozenAccounts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, T::CurrencyId, bool, ValueQuery>;
+
+	/// The metadata of the registered token types.
+	#[pallet::storage]
+	#[pallet::getter(fn currency_metadata)]
+	pub type Metadata<T: Config> =
+		StorageMap<_, Twox64Concat, T::CurrencyId, CurrencyMetadata<T::Balance, BoundedVec<u8, T::StringLimit>>>;
+
+	/// The issuer and admin of a token type.
+	#[pallet::storage]
+	#[pallet::getter(fn roles)]
+	pub type Roles<T: Config> = StorageMap<_, Twox64Concat, T::CurrencyId, CurrencyRoles<T::AccountId>>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub balances: Vec<(T::AccountId, T::CurrencyId, T::Balance)>,
+		/// Registered currencies as `(currency_id, name, symbol, decimals,
+		/// existential_deposit)`.
+		pub metadata: Vec<(T::CurrencyId, Vec<u8>, Vec<u8>, u8, T::Balance)>,
 	}
 
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			GenesisConfig { balances: vec![] }
+			GenesisConfig {
+				balances: vec![],
+				metadata: vec![],
+			}
 		}
 	}
 
 	#[pallet::genesis_build]
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
+			// register currencies first, their existential deposits may apply to the
+			// endowed accounts.
+			self.metadata
+				.iter()
+				.for_each(|(currency_id, name, symbol, decimals, existential_deposit)| {
+					assert!(
+						!Metadata::<T>::contains_key(currency_id),
+						"duplicate registered currencies in genesis."
+					);
+					Pallet::<T>::do_set_currency_metadata(
+						*currency_id,
+						name.clone(),
+						symbol.clone(),
+						*decimals,
+						*existential_deposit,
+					)
+					.expect("currency name and symbol should be within the string limit");
+				});
+
 			// ensure no duplicates exist.
 			let unique_endowed_accounts = self
 				.balances
@@ -337,7 +521,8 @@ pub mod module {
 			Ok(().into())
 		}
 
-		/// Transfer all remaining balance to the given account.
+		/// Transfer all remaining balance to the given account. The locked
+		/// balance stays with the transactor.
 		///
 		/// The dispatch origin for this call must be `Signed` by the
 		/// transactor.
@@ -349,12 +534,220 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(dest)?;
-			let balance = <Self as MultiCurrency<T::AccountId>>::free_balance(currency_id, &from);
+			let balance = <Self as MultiCurrency<T::AccountId>>::free_balance(currency_id, &from)
+				.saturating_sub(Self::accounts(&from, currency_id).frozen());
 			<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, &from, &to, balance)?;
 
 			Self::deposit_event(Event::Transfer(currency_id, from, to, balance));
 			Ok(().into())
 		}
+
+		/// Set the paused operations of `currency_id`. Resuming every
+		/// operation removes the entry.
+		///
+		/// The dispatch origin for this call must be `PauseOrigin`.
+		#[pallet::weight(T::WeightInfo::set_pause_status())]
+		pub fn set_pause_status(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			status: PauseStatus,
+		) -> DispatchResultWithPostInfo {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			if status.is_paused() {
+				CurrencyPauseStatus::<T>::insert(currency_id, status);
+			} else {
+				CurrencyPauseStatus::<T>::remove(currency_id);
+			}
+
+			Self::deposit_event(Event::PauseStatusChanged(currency_id, status));
+			Ok(().into())
+		}
+
+		/// Freeze the free balance of `who` in `currency_id`. Deposits are
+		/// still accepted.
+		///
+		/// The dispatch origin for this call must be `FreezeOrigin`.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			currency_id: T::CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			FrozenAccounts::<T>::insert(&who, currency_id, true);
+
+			Self::deposit_event(Event::Frozen(currency_id, who));
+			Ok(().into())
+		}
+
+		/// Allow the free balance of `who` in `currency_id` to move again.
+		///
+		/// The dispatch origin for this call must be `FreezeOrigin`.
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			currency_id: T::CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			FrozenAccounts::<T>::remove(&who, currency_id);
+
+			Self::deposit_event(Event::Thawed(currency_id, who));
+			Ok(().into())
+		}
+
+		/// Register `currency_id` or update its metadata. Newly registered
+		/// currencies are `Active`.
+		///
+		/// NOTE: Changing the existential deposit doesn't affect existing
+		/// accounts until their balance changes.
+		///
+		/// The dispatch origin for this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::set_currency_metadata())]
+		pub fn set_currency_metadata(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			#[pallet::compact] existential_deposit: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let registered =
+				Self::do_set_currency_metadata(currency_id, name, symbol, decimals, existential_deposit)?;
+
+			if registered {
+				Self::deposit_event(Event::CurrencyRegistered(currency_id));
+			} else {
+				Self::deposit_event(Event::CurrencyMetadataUpdated(currency_id));
+			}
+			Ok(().into())
+		}
+
+		/// Set the status of the registered `currency_id`.
+		///
+		/// The dispatch origin for this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::set_currency_status())]
+		pub fn set_currency_status(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			status: CurrencyStatus,
+		) -> DispatchResultWithPostInfo {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			Metadata::<T>::try_mutate(currency_id, |maybe_metadata| -> DispatchResult {
+				let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::CurrencyNotRegistered)?;
+				metadata.status = status;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CurrencyStatusChanged(currency_id, status));
+			Ok(().into())
+		}
+
+		/// Set the issuer and admin of `currency_id`.
+		///
+		/// The dispatch origin for this call must be `RegisterOrigin` or
+		/// `Signed` by the current admin of the currency.
+		#[pallet::weight(T::WeightInfo::set_issuer())]
+		pub fn set_issuer(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			if let Err(origin) = T::RegisterOrigin::try_origin(origin) {
+				Self::ensure_admin(origin, currency_id)?;
+			}
+			let issuer = T::Lookup::lookup(issuer)?;
+			let admin = T::Lookup::lookup(admin)?;
+
+			Roles::<T>::insert(
+				currency_id,
+				CurrencyRoles {
+					issuer: issuer.clone(),
+					admin: admin.clone(),
+				},
+			);
+
+			Self::deposit_event(Event::IssuerSet(currency_id, issuer, admin));
+			Ok(().into())
+		}
+
+		/// Mint `amount` of `currency_id` into the free balance of `dest`,
+		/// increasing the total issuance.
+		///
+		/// The dispatch origin for this call must be `Signed` by the issuer
+		/// of the currency.
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: T::CurrencyId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Self::roles(currency_id).map_or(false, |roles| roles.issuer == who),
+				Error::<T>::NoPermission
+			);
+			let dest = T::Lookup::lookup(dest)?;
+
+			<Self as fungibles::Mutate<_>>::mint_into(currency_id, &dest, amount)?;
+
+			Self::deposit_event(Event::Minted(currency_id, dest, amount));
+			Ok(().into())
+		}
+
+		/// Burn `amount` of `currency_id` from the free balance of `who`,
+		/// decreasing the total issuance. Locks, freezes and pauses apply.
+		///
+		/// The dispatch origin for this call must be `Signed` by the admin
+		/// of the currency.
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			currency_id: T::CurrencyId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_admin(origin, currency_id)?;
+			let who = T::Lookup::lookup(who)?;
+
+			<Self as MultiCurrency<_>>::withdraw(currency_id, &who, amount)?;
+
+			Self::deposit_event(Event::Burned(currency_id, who, amount));
+			Ok(().into())
+		}
+
+		/// Transfer `amount` of `currency_id` from `source` to `dest`. Locks,
+		/// freezes and pauses apply.
+		///
+		/// The dispatch origin for this call must be `Signed` by the admin
+		/// of the currency.
+		#[pallet::weight(T::WeightInfo::force_transfer())]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			source: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: T::CurrencyId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_admin(origin, currency_id)?;
+			let from = T::Lookup::lookup(source)?;
+			let to = T::Lookup::lookup(dest)?;
+
+			<Self as MultiCurrency<_>>::transfer(currency_id, &from, &to, amount)?;
+
+			Self::deposit_event(Event::Transfer(currency_id, from, to, amount));
+			Ok(().into())
+		}
 	}
 }
 
@@ -364,6 +757,45 @@ impl<T: Config> Pallet<T> {
 		PalletId::try_from_account(account_id).is_some()
 	}
 
+	/// Ensure `origin` is `Signed` by the admin of `currency_id`.
+	fn ensure_admin(origin: OriginFor<T>, currency_id: T::CurrencyId) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+		ensure!(
+			Self::roles(currency_id).map_or(false, |roles| roles.admin == who),
+			Error::<T>::NoPermission
+		);
+		Ok(())
+	}
+
+	/// Register `currency_id` or update its metadata, keeping the status of
+	/// registered currencies. Returns whether the currency was newly
+	/// registered.
+	pub(crate) fn do_set_currency_metadata(
+		currency_id: T::CurrencyId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		decimals: u8,
+		existential_deposit: T::Balance,
+	) -> Result<bool, DispatchError> {
+		let name: BoundedVec<u8, T::StringLimit> = name.try_into().map_err(|_| Error::<T>::BadMetadata)?;
+		let symbol: BoundedVec<u8, T::StringLimit> = symbol.try_into().map_err(|_| Error::<T>::BadMetadata)?;
+
+		Ok(Metadata::<T>::mutate(currency_id, |maybe_metadata| {
+			let registered = maybe_metadata.is_none();
+			let status = maybe_metadata
+				.as_ref()
+				.map_or(CurrencyStatus::Active, |metadata| metadata.status);
+			*maybe_metadata = Some(CurrencyMetadata {
+				name,
+				symbol,
+				decimals,
+				existential_deposit,
+				status,
+			});
+			registered
+		}))
+	}
+
 	pub(crate) fn deposit_consequence(
 		_who: &T::AccountId,
 		currency_id: T::CurrencyId,
@@ -403,6 +835,10 @@ impl<T: Config> Pallet<T> {
 			return WithdrawConsequence::Success;
 		}
 
+		if Self::is_frozen(who, currency_id) {
+			return WithdrawConsequence::Frozen;
+		}
+
 		if TotalIssuance::<T>::get(currency_id).checked_sub(&amount).is_none() {
 			return WithdrawConsequence::Underflow;
 		}
@@ -583,6 +1019,7 @@ impl<T: Config> Pallet<T> {
 		if amount.is_zero() || from == to {
 			return Ok(());
 		}
+		ensure!(!Self::pause_status(currency_id).transfer, Error::<T>::TransferPaused);
 
 		Pallet::<T>::try_mutate_account(to, currency_id, |to_account, _existed| -> DispatchResult {
 			Pallet::<T>::try_mutate_account(from, currency_id, |from_account, _existed| -> DispatchResult {
@@ -636,12 +1073,13 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	}
 
 	// Ensure that an account can withdraw from their free balance given any
-	// existing withdrawal restrictions like locks and vesting balance.
+	// existing withdrawal restrictions like locks, vesting balance and freezes.
 	// Is a no-op if amount to be withdrawn is zero.
 	fn ensure_can_withdraw(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(());
 		}
+		ensure!(!Self::is_frozen(who, currency_id), Error::<T>::AccountFrozen);
 
 		let new_balance = Self::free_balance(currency_id, who)
 			.checked_sub(&amount)
@@ -672,6 +1110,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() {
 			return Ok(());
 		}
+		ensure!(!Self::pause_status(currency_id).deposit, Error::<T>::DepositPaused);
 
 		TotalIssuance::<T>::try_mutate(currency_id, |total_issuance| -> DispatchResult {
 			*total_issuance = total_issuance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
@@ -686,6 +1125,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() {
 			return Ok(());
 		}
+		ensure!(!Self::pause_status(currency_id).withdraw, Error::<T>::WithdrawPaused);
 		Self::ensure_can_withdraw(currency_id, who, amount)?;
 
 		// Cannot underflow because ensure_can_withdraw check
@@ -914,6 +1354,8 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 	/// - the value to be moved is zero; or
 	/// - the `slashed` id equal to `beneficiary` and the `status` is
 	///   `Reserved`.
+	///
+	/// Fails if transfers of the currency are paused.
 	fn repatriate_reserved(
 		currency_id: Self::CurrencyId,
 		slashed: &T::AccountId,
@@ -931,6 +1373,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				BalanceStatus::Reserved => Ok(value.saturating_sub(Self::reserved_balance(currency_id, slashed))),
 			};
 		}
+		ensure!(!Self::pause_status(currency_id).transfer, Error::<T>::TransferPaused);
 
 		let from_account = Self::accounts(slashed, currency_id);
 		let to_account = Self::accounts(beneficiary, currency_id);
@@ -962,6 +1405,9 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 		Pallet::<T>::total_balance(asset_id, who)
 	}
 	fn reducible_balance(asset_id: Self::AssetId, who: &T::AccountId, keep_alive: bool) -> Self::Balance {
+		if Pallet::<T>::pause_status(asset_id).withdraw || Pallet::<T>::is_frozen(who, asset_id) {
+			return Zero::zero();
+		}
 		let a = Pallet::<T>::accounts(who, asset_id);
 		// Liquid balance is what is neither reserved nor locked/frozen.
 		let liquid = a.free.saturating_sub(a.frozen);
@@ -975,6 +1421,9 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 		}
 	}
 	fn can_deposit(asset_id: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> DepositConsequence {
+		if !amount.is_zero() && Pallet::<T>::pause_status(asset_id).deposit {
+			return DepositConsequence::CannotCreate;
+		}
 		Pallet::<T>::deposit_consequence(who, asset_id, amount, &Pallet::<T>::accounts(who, asset_id))
 	}
 	fn can_withdraw(
@@ -982,6 +1431,9 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> WithdrawConsequence<Self::Balance> {
+		if !amount.is_zero() && Pallet::<T>::pause_status(asset_id).withdraw {
+			return WithdrawConsequence::Frozen;
+		}
 		Pallet::<T>::withdraw_consequence(who, asset_id, amount, &Pallet::<T>::accounts(who, asset_id))
 	}
 }
@@ -991,6 +1443,7 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 		if amount.is_zero() {
 			return Ok(());
 		}
+		ensure!(!Pallet::<T>::pause_status(asset_id).deposit, Error::<T>::DepositPaused);
 		Pallet::<T>::try_mutate_account(who, asset_id, |account, _existed| -> DispatchResult {
 			Pallet::<T>::deposit_consequence(who, asset_id, amount, &account).into_result()?;
 			// deposit_consequence already did overflow checking
@@ -1010,6 +1463,7 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 		if amount.is_zero() {
 			return Ok(Self::Balance::zero());
 		}
+		ensure!(!Pallet::<T>::pause_status(asset_id).withdraw, Error::<T>::WithdrawPaused);
 		let actual = Pallet::<T>::try_mutate_account(
 			who,
 			asset_id,
@@ -1046,6 +1500,10 @@ impl<T: Config> fungibles::Transfer<T::AccountId> for Pallet<T> {
 
 impl<T: Config> fungibles::Unbalanced<T::AccountId> for Pallet<T> {
 	fn set_balance(asset_id: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		let status = Pallet::<T>::pause_status(asset_id);
+		let free = Pallet::<T>::accounts(who, asset_id).free;
+		ensure!(amount <= free || !status.deposit, Error::<T>::DepositPaused);
+		ensure!(amount >= free || !status.withdraw, Error::<T>::WithdrawPaused);
 		// Balance is the same type and will not overflow
 		Pallet::<T>::mutate_account(who, asset_id, |account, _| account.free = amount);
 		Ok(())
@@ -1062,6 +1520,9 @@ impl<T: Config> fungibles::InspectHold<T::AccountId> for Pallet<T> {
 		Pallet::<T>::accounts(who, asset_id).reserved
 	}
 	fn can_hold(asset_id: Self::AssetId, who: &T::AccountId, amount: T::Balance) -> bool {
+		if Pallet::<T>::is_frozen(who, asset_id) {
+			return false;
+		}
 		let a = Pallet::<T>::accounts(who, asset_id);
 		let min_balance = T::ExistentialDeposits::get(&asset_id).max(a.frozen);
 		if a.reserved.checked_add(&amount).is_none() {
@@ -1123,6 +1584,7 @@ impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {
 		_best_effort: bool,
 		on_hold: bool,
 	) -> Result<Self::Balance, DispatchError> {
+		ensure!(!Pallet::<T>::pause_status(asset_id).transfer, Error::<T>::TransferPaused);
 		let status = if on_hold { Status::Reserved } else { Status::Free };
 		Pallet::<T>::repatriate_reserved(asset_id, source, dest, amount, status)
 	}
@@ -1240,6 +1702,7 @@ where
 			return Ok(Self::PositiveImbalance::zero());
 		}
 		let currency_id = GetCurrencyId::get();
+		ensure!(!Pallet::<T>::pause_status(currency_id).deposit, Error::<T>::DepositPaused);
 		let new_total = Pallet::<T>::free_balance(currency_id, who)
 			.checked_add(&value)
 			.ok_or(ArithmeticError::Overflow)?;
@@ -1262,6 +1725,7 @@ where
 			return Ok(Self::NegativeImbalance::zero());
 		}
 		let currency_id = GetCurrencyId::get();
+		ensure!(!Pallet::<T>::pause_status(currency_id).withdraw, Error::<T>::WithdrawPaused);
 		Pallet::<T>::try_mutate_account(who, currency_id, |account, _existed| -> DispatchResult {
 			account.free = account.free.checked_sub(&value).ok_or(Error::<T>::BalanceTooLow)?;
 
@@ -1297,6 +1761,11 @@ where
 				let ed = T::ExistentialDeposits::get(&currency_id);
 				ensure!(value.saturating_add(account.reserved) >= ed || existed, ());
 
+				// raising the balance deposits, lowering it withdraws
+				let status = Pallet::<T>::pause_status(currency_id);
+				ensure!(value <= account.free || !status.deposit, ());
+				ensure!(value >= account.free || !status.withdraw, ());
+
 				let imbalance = if account.free <= value {
 					SignedImbalance::Positive(PositiveImbalance::new(value - account.free))
 				} else {
@@ -209,6 +209,7 @@ parameter_type_with_key! {
 
 parameter_types! {
 	pub DustAccount: AccountId = PalletId(*b"orml/dst").into_account();
+	pub const StringLimit: u32 = 8;
 	pub MaxLocks: u32 = 2;
 }
 
@@ -221,6 +222,10 @@ impl Config for Runtime {
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = TransferDust<Runtime, DustAccount>;
 	type MaxLocks = MaxLocks;
+	type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+	type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+	type RegisterOrigin = frame_system::EnsureRoot<AccountId>;
+	type StringLimit = StringLimit;
 }
 pub type TreasuryCurrencyAdapter = <Runtime as pallet_treasury::Config>::Currency;
 
@@ -242,6 +247,7 @@ construct_runtime!(
 
 pub struct ExtBuilder {
 	balances: Vec<(AccountId, CurrencyId, Balance)>,
+	metadata: Vec<(CurrencyId, Vec<u8>, Vec<u8>, u8, Balance)>,
 	treasury_genesis: bool,
 }
 
@@ -249,6 +255,7 @@ impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			balances: vec![],
+			metadata: vec![],
 			treasury_genesis: false,
 		}
 	}
@@ -260,6 +267,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn metadata(mut self, metadata: Vec<(CurrencyId, Vec<u8>, Vec<u8>, u8, Balance)>) -> Self {
+		self.metadata = metadata;
+		self
+	}
+
 	pub fn one_hundred_for_alice_n_bob(self) -> Self {
 		self.balances(vec![(ALICE, DOT, 100), (BOB, DOT, 100)])
 	}
@@ -276,6 +288,7 @@ impl ExtBuilder {
 
 		tokens::GenesisConfig::<Runtime> {
 			balances: self.balances,
+			metadata: self.metadata,
 		}
 			.assimilate_storage(&mut t)
 			.unwrap();
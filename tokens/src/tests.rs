@@ -345,6 +345,12 @@ fn transfer_all_should_work() {
 			assert_eq!(Tokens::free_balance(DOT, &BOB), 200);
 
 			System::assert_last_event(Event::Tokens(crate::Event::Transfer(DOT, ALICE, BOB, 100)));
+
+			// the locked balance is kept
+			assert_ok!(Tokens::set_lock(ID_1, DOT, &BOB, 50));
+			assert_ok!(Tokens::transfer_all(Some(BOB).into(), ALICE, DOT));
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 150);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 50);
 		});
 }
 
@@ -1099,3 +1105,415 @@ fn fungibles_mutate_hold_trait_should_work() {
 			);
 		});
 }
+
+#[test]
+fn set_pause_status_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let status = PauseStatus {
+			transfer: true,
+			deposit: false,
+			withdraw: true,
+		};
+		assert_noop!(
+			Tokens::set_pause_status(Some(ALICE).into(), DOT, status),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Tokens::set_pause_status(Origin::root(), DOT, status));
+		assert_eq!(Tokens::pause_status(DOT), status);
+		assert_eq!(Tokens::pause_status(BTC), Default::default());
+		System::assert_last_event(Event::Tokens(crate::Event::PauseStatusChanged(DOT, status)));
+
+		assert_ok!(Tokens::set_pause_status(Origin::root(), DOT, Default::default()));
+		assert_eq!(CurrencyPauseStatus::<Runtime>::contains_key(DOT), false);
+		System::assert_last_event(Event::Tokens(crate::Event::PauseStatusChanged(
+			DOT,
+			Default::default(),
+		)));
+	});
+}
+
+#[test]
+fn paused_transfer_should_fail() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			let status = PauseStatus {
+				transfer: true,
+				..Default::default()
+			};
+			assert_ok!(Tokens::set_pause_status(Origin::root(), DOT, status));
+
+			assert_noop!(
+				Tokens::transfer(Some(ALICE).into(), BOB, DOT, 50),
+				Error::<Runtime>::TransferPaused
+			);
+			assert_noop!(
+				Tokens::transfer_all(Some(ALICE).into(), BOB, DOT),
+				Error::<Runtime>::TransferPaused
+			);
+			assert_noop!(
+				<Tokens as fungibles::Transfer<_>>::transfer(DOT, &ALICE, &BOB, 50, true),
+				Error::<Runtime>::TransferPaused
+			);
+			assert_noop!(
+				TreasuryCurrencyAdapter::transfer(&ALICE, &BOB, 50, ExistenceRequirement::AllowDeath),
+				Error::<Runtime>::TransferPaused
+			);
+
+			assert_ok!(Tokens::reserve(DOT, &ALICE, 50));
+			assert_noop!(
+				Tokens::repatriate_reserved(DOT, &ALICE, &BOB, 50, BalanceStatus::Free),
+				Error::<Runtime>::TransferPaused
+			);
+			assert_noop!(
+				TreasuryCurrencyAdapter::repatriate_reserved(&ALICE, &BOB, 50, Status::Reserved),
+				Error::<Runtime>::TransferPaused
+			);
+			// moving the own reserve is not a transfer
+			assert_eq!(
+				Tokens::repatriate_reserved(DOT, &ALICE, &ALICE, 50, BalanceStatus::Free),
+				Ok(0)
+			);
+
+			// other operations and currencies are unaffected
+			assert_ok!(Tokens::deposit(DOT, &ALICE, 10));
+			assert_ok!(Tokens::withdraw(DOT, &ALICE, 10));
+			assert_ok!(Tokens::deposit(BTC, &ALICE, 10));
+			assert_ok!(Tokens::transfer(Some(ALICE).into(), BOB, BTC, 5));
+
+			assert_ok!(Tokens::set_pause_status(Origin::root(), DOT, Default::default()));
+			assert_ok!(Tokens::transfer(Some(ALICE).into(), BOB, DOT, 50));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 150);
+		});
+}
+
+#[test]
+fn paused_deposit_and_withdraw_should_fail() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			let status = PauseStatus {
+				deposit: true,
+				withdraw: true,
+				..Default::default()
+			};
+			assert_ok!(Tokens::set_pause_status(Origin::root(), DOT, status));
+
+			assert_noop!(Tokens::deposit(DOT, &ALICE, 10), Error::<Runtime>::DepositPaused);
+			assert_noop!(Tokens::withdraw(DOT, &ALICE, 10), Error::<Runtime>::WithdrawPaused);
+			assert_noop!(
+				<Tokens as fungibles::Mutate<_>>::mint_into(DOT, &ALICE, 10),
+				Error::<Runtime>::DepositPaused
+			);
+			assert_noop!(
+				<Tokens as fungibles::Mutate<_>>::burn_from(DOT, &ALICE, 10),
+				Error::<Runtime>::WithdrawPaused
+			);
+			assert_noop!(
+				TreasuryCurrencyAdapter::deposit_into_existing(&ALICE, 10).map(|_| ()),
+				Error::<Runtime>::DepositPaused
+			);
+			assert_noop!(
+				<Tokens as fungibles::Unbalanced<_>>::set_balance(DOT, &ALICE, 110),
+				Error::<Runtime>::DepositPaused
+			);
+			assert_noop!(
+				<Tokens as fungibles::Unbalanced<_>>::set_balance(DOT, &ALICE, 90),
+				Error::<Runtime>::WithdrawPaused
+			);
+			assert_ok!(<Tokens as fungibles::Unbalanced<_>>::set_balance(DOT, &ALICE, 100));
+			for value in vec![110, 90] {
+				match TreasuryCurrencyAdapter::make_free_balance_be(&ALICE, value) {
+					SignedImbalance::Positive(imbalance) => assert_eq!(imbalance.peek(), 0),
+					SignedImbalance::Negative(_) => panic!("balance should not be lowered"),
+				}
+				assert_eq!(Tokens::free_balance(DOT, &ALICE), 100);
+			}
+			assert_eq!(
+				<Tokens as fungibles::Inspect<_>>::can_deposit(DOT, &ALICE, 1),
+				DepositConsequence::CannotCreate
+			);
+			assert_eq!(
+				<Tokens as fungibles::Inspect<_>>::can_withdraw(DOT, &ALICE, 1),
+				WithdrawConsequence::Frozen
+			);
+			assert_eq!(
+				<Tokens as fungibles::Inspect<_>>::reducible_balance(DOT, &ALICE, false),
+				0
+			);
+
+			// transfers keep the issuance unchanged and are still allowed
+			assert_ok!(Tokens::transfer(Some(ALICE).into(), BOB, DOT, 50));
+			assert_eq!(Tokens::total_issuance(DOT), 200);
+		});
+}
+
+#[test]
+fn freeze_and_thaw_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Tokens::freeze(Some(ALICE).into(), BOB, DOT),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Tokens::freeze(Origin::root(), BOB, DOT));
+		assert_eq!(Tokens::is_frozen(&BOB, DOT), true);
+		assert_eq!(Tokens::is_frozen(&BOB, BTC), false);
+		System::assert_last_event(Event::Tokens(crate::Event::Frozen(DOT, BOB)));
+
+		assert_noop!(Tokens::thaw(Some(ALICE).into(), BOB, DOT), DispatchError::BadOrigin);
+		assert_ok!(Tokens::thaw(Origin::root(), BOB, DOT));
+		assert_eq!(FrozenAccounts::<Runtime>::contains_key(&BOB, DOT), false);
+		System::assert_last_event(Event::Tokens(crate::Event::Thawed(DOT, BOB)));
+	});
+}
+
+#[test]
+fn frozen_account_cannot_move_free_balance() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Tokens::reserve(DOT, &ALICE, 30));
+			assert_ok!(Tokens::freeze(Origin::root(), ALICE, DOT));
+
+			assert_noop!(
+				Tokens::transfer(Some(ALICE).into(), BOB, DOT, 10),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_noop!(Tokens::withdraw(DOT, &ALICE, 10), Error::<Runtime>::AccountFrozen);
+			assert_noop!(Tokens::reserve(DOT, &ALICE, 10), Error::<Runtime>::AccountFrozen);
+			assert_noop!(
+				Tokens::ensure_can_withdraw(DOT, &ALICE, 1),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_eq!(
+				<Tokens as fungibles::Inspect<_>>::reducible_balance(DOT, &ALICE, false),
+				0
+			);
+			assert_eq!(
+				<Tokens as fungibles::Inspect<_>>::can_withdraw(DOT, &ALICE, 1),
+				WithdrawConsequence::Frozen
+			);
+			assert_eq!(<Tokens as fungibles::InspectHold<_>>::can_hold(DOT, &ALICE, 1), false);
+
+			// the frozen account still receives, other currencies are unaffected
+			assert_ok!(Tokens::transfer(Some(BOB).into(), ALICE, DOT, 10));
+			assert_ok!(Tokens::deposit(BTC, &ALICE, 10));
+			assert_ok!(Tokens::transfer(Some(ALICE).into(), BOB, BTC, 5));
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 80);
+
+			// reserves and slashing still work
+			assert_eq!(Tokens::unreserve(DOT, &ALICE, 10), 0);
+			assert_eq!(Tokens::repatriate_reserved(DOT, &ALICE, &BOB, 10, BalanceStatus::Free), Ok(0));
+			assert_eq!(Tokens::slash_reserved(DOT, &ALICE, 5), 0);
+			assert_eq!(Tokens::slash(DOT, &ALICE, 10), 0);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 80);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 5);
+
+			assert_ok!(Tokens::thaw(Origin::root(), ALICE, DOT));
+			assert_ok!(Tokens::transfer(Some(ALICE).into(), BOB, DOT, 10));
+		});
+}
+
+#[test]
+fn set_currency_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Tokens::set_currency_metadata(Some(ALICE).into(), ETH, b"Ether".to_vec(), b"ETH".to_vec(), 18, 5),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Tokens::set_currency_metadata(Origin::root(), ETH, b"Ethereum Ether".to_vec(), b"ETH".to_vec(), 18, 5),
+			Error::<Runtime>::BadMetadata
+		);
+
+		assert_ok!(Tokens::set_currency_metadata(
+			Origin::root(),
+			ETH,
+			b"Ether".to_vec(),
+			b"ETH".to_vec(),
+			18,
+			5
+		));
+		System::assert_last_event(Event::Tokens(crate::Event::CurrencyRegistered(ETH)));
+		let metadata = Tokens::currency_metadata(ETH).unwrap();
+		assert_eq!(metadata.name.to_vec(), b"Ether".to_vec());
+		assert_eq!(metadata.symbol.to_vec(), b"ETH".to_vec());
+		assert_eq!(metadata.decimals, 18);
+		assert_eq!(metadata.existential_deposit, 5);
+		assert_eq!(metadata.status, CurrencyStatus::Active);
+
+		assert_ok!(Tokens::set_currency_status(Origin::root(), ETH, CurrencyStatus::Deprecated));
+		System::assert_last_event(Event::Tokens(crate::Event::CurrencyStatusChanged(
+			ETH,
+			CurrencyStatus::Deprecated,
+		)));
+
+		// updating keeps the status
+		assert_ok!(Tokens::set_currency_metadata(
+			Origin::root(),
+			ETH,
+			b"Ether".to_vec(),
+			b"ETH".to_vec(),
+			18,
+			7
+		));
+		System::assert_last_event(Event::Tokens(crate::Event::CurrencyMetadataUpdated(ETH)));
+		let metadata = Tokens::currency_metadata(ETH).unwrap();
+		assert_eq!(metadata.existential_deposit, 7);
+		assert_eq!(metadata.status, CurrencyStatus::Deprecated);
+	});
+}
+
+#[test]
+fn set_currency_status_should_fail_for_unregistered_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Tokens::set_currency_status(Some(ALICE).into(), ETH, CurrencyStatus::Deprecated),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Tokens::set_currency_status(Origin::root(), ETH, CurrencyStatus::Deprecated),
+			Error::<Runtime>::CurrencyNotRegistered
+		);
+	});
+}
+
+#[test]
+fn registered_existential_deposits_should_work() {
+	ExtBuilder::default()
+		.metadata(vec![(ETH, b"Ether".to_vec(), b"ETH".to_vec(), 18, 5)])
+		.build()
+		.execute_with(|| {
+			type RegisteredDeposits = RegisteredExistentialDeposits<Runtime, ExistentialDeposits>;
+
+			assert_eq!(Tokens::currency_metadata(ETH).unwrap().status, CurrencyStatus::Active);
+			assert_eq!(RegisteredDeposits::get(&ETH), 5);
+			// unregistered currencies fall back
+			assert_eq!(RegisteredDeposits::get(&DOT), 2);
+
+			assert_ok!(Tokens::set_currency_metadata(
+				Origin::root(),
+				DOT,
+				b"Polkadot".to_vec(),
+				b"DOT".to_vec(),
+				10,
+				10
+			));
+			assert_eq!(RegisteredDeposits::get(&DOT), 10);
+		});
+}
+
+#[test]
+fn set_issuer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Tokens::set_issuer(Some(ALICE).into(), DOT, ALICE, ALICE),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(Tokens::set_issuer(Origin::root(), DOT, ALICE, BOB));
+		assert_eq!(
+			Tokens::roles(DOT),
+			Some(CurrencyRoles {
+				issuer: ALICE,
+				admin: BOB
+			})
+		);
+		System::assert_last_event(Event::Tokens(crate::Event::IssuerSet(DOT, ALICE, BOB)));
+
+		// only the admin changes the roles
+		assert_noop!(
+			Tokens::set_issuer(Some(ALICE).into(), DOT, ALICE, ALICE),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Tokens::set_issuer(Some(BOB).into(), DOT, CHARLIE, CHARLIE));
+		assert_eq!(
+			Tokens::roles(DOT),
+			Some(CurrencyRoles {
+				issuer: CHARLIE,
+				admin: CHARLIE
+			})
+		);
+		assert_eq!(Tokens::roles(BTC), None);
+	});
+}
+
+#[test]
+fn mint_and_burn_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Tokens::set_issuer(Origin::root(), DOT, ALICE, BOB));
+
+			assert_noop!(
+				Tokens::mint(Some(BOB).into(), CHARLIE, DOT, 50),
+				Error::<Runtime>::NoPermission
+			);
+			assert_noop!(
+				Tokens::mint(Some(ALICE).into(), CHARLIE, BTC, 50),
+				Error::<Runtime>::NoPermission
+			);
+			assert_ok!(Tokens::mint(Some(ALICE).into(), CHARLIE, DOT, 50));
+			assert_eq!(Tokens::free_balance(DOT, &CHARLIE), 50);
+			assert_eq!(Tokens::total_issuance(DOT), 250);
+			System::assert_last_event(Event::Tokens(crate::Event::Minted(DOT, CHARLIE, 50)));
+
+			assert_noop!(
+				Tokens::burn(Some(ALICE).into(), CHARLIE, DOT, 20),
+				Error::<Runtime>::NoPermission
+			);
+			assert_ok!(Tokens::burn(Some(BOB).into(), CHARLIE, DOT, 20));
+			assert_eq!(Tokens::free_balance(DOT, &CHARLIE), 30);
+			assert_eq!(Tokens::total_issuance(DOT), 230);
+			System::assert_last_event(Event::Tokens(crate::Event::Burned(DOT, CHARLIE, 20)));
+
+			// pauses apply
+			let status = PauseStatus {
+				deposit: true,
+				withdraw: true,
+				..Default::default()
+			};
+			assert_ok!(Tokens::set_pause_status(Origin::root(), DOT, status));
+			assert_noop!(
+				Tokens::mint(Some(ALICE).into(), CHARLIE, DOT, 50),
+				Error::<Runtime>::DepositPaused
+			);
+			assert_noop!(
+				Tokens::burn(Some(BOB).into(), CHARLIE, DOT, 20),
+				Error::<Runtime>::WithdrawPaused
+			);
+		});
+}
+
+#[test]
+fn force_transfer_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Tokens::set_issuer(Origin::root(), DOT, ALICE, BOB));
+
+			assert_noop!(
+				Tokens::force_transfer(Some(ALICE).into(), BOB, CHARLIE, DOT, 50),
+				Error::<Runtime>::NoPermission
+			);
+			assert_ok!(Tokens::force_transfer(Some(BOB).into(), ALICE, CHARLIE, DOT, 50));
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 50);
+			assert_eq!(Tokens::free_balance(DOT, &CHARLIE), 50);
+			assert_eq!(Tokens::total_issuance(DOT), 200);
+			System::assert_last_event(Event::Tokens(crate::Event::Transfer(DOT, ALICE, CHARLIE, 50)));
+
+			// freezes apply
+			assert_ok!(Tokens::freeze(Origin::root(), ALICE, DOT));
+			assert_noop!(
+				Tokens::force_transfer(Some(BOB).into(), ALICE, CHARLIE, DOT, 10),
+				Error::<Runtime>::AccountFrozen
+			);
+		});
+}
@@ -1,11 +1,13 @@
-//! Autogenerated weights for orml_tokens
+//! Weights for orml_tokens
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
-//! DATE: 2021-05-04, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//! `transfer` and `transfer_all` start from the ORML benchmark run of
+//! 2021-05-04 (STEPS: 50, REPEAT: 20, CHAIN: dev) with the reads of
+//! `CurrencyPauseStatus` and `FrozenAccounts` added. The other weights are
+//! estimates. Regenerate all of them from `benchmarking.rs` on reference
+//! hardware.
 
-// Executed Command:
-// /Users/xiliangchen/projects/acala/target/release/acala
+// Command to regenerate:
+// ./target/release/polkafoundry
 // benchmark
 // --chain=dev
 // --steps=50
@@ -32,18 +34,83 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn transfer() -> Weight;
 	fn transfer_all() -> Weight;
+	fn set_lock(l: u32, ) -> Weight;
+	fn remove_lock(l: u32, ) -> Weight;
+	fn set_pause_status() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn set_currency_metadata() -> Weight;
+	fn set_currency_status() -> Weight;
+	fn set_issuer() -> Weight;
+	fn mint() -> Weight;
+	fn burn() -> Weight;
+	fn force_transfer() -> Weight;
 }
 
 /// Default weights.
 impl WeightInfo for () {
 	fn transfer() -> Weight {
 		(60_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
 	fn transfer_all() -> Weight {
 		(62_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
+	fn set_lock(l: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((450_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn remove_lock(l: u32, ) -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add((420_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn set_pause_status() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_currency_metadata() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_currency_status() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_issuer() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn mint() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn burn() -> Weight {
+		(62_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn force_transfer() -> Weight {
+		(72_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
 }
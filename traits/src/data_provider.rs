@@ -16,9 +16,9 @@ pub trait DataProvider<Key, Value> {
 /// Extended data provider to provide timestamped data by key with no-op, and
 /// all data.
 pub trait DataProviderExtended<Key, AccountId, Value> {
-	/// Get timestamped value by key
-	fn get_polkafoundry(key: &Key, feeder: AccountId) -> Option<Value>;
-	/// Get timestamped value by key
+	/// Get timestamped value by key from the first-party feeders
+	fn get_polkafoundry(key: &Key) -> Option<Value>;
+	/// Get timestamped value by key from a feeder
 	fn get_concrete(key: &Key, feeder: AccountId) -> Option<Value>;
 	/// Provide a list of tuples of key and timestamped value
 	fn get_all_values() -> Vec<(Key, Option<Value>)>;
@@ -37,9 +37,12 @@ pub fn median<T: Ord + Clone>(mut items: Vec<T>) -> Option<T> {
 	Some(item.clone())
 }
 
+/// Create a provider returning the median of the values of `$provider`s,
+/// e.g. several oracle instances. Per-feeder lookups return the median of
+/// the values of the feeder in each provider.
 #[macro_export]
 macro_rules! create_median_value_data_provider {
-	($name:ident, $key:ty, $value:ty, $timestamped_value:ty, [$( $provider:ty ),*]) => {
+	($name:ident, $key:ty, $account_id:ty, $value:ty, $timestamped_value:ty, [$( $provider:ty ),*]) => {
 		pub struct $name;
 		impl $crate::DataProvider<$key, $value> for $name {
 			fn get(key: &$key) -> Option<$value> {
@@ -52,24 +55,38 @@ macro_rules! create_median_value_data_provider {
 				$crate::data_provider::median(values)
 			}
 		}
-		impl $crate::DataProviderExtended<$key, $timestamped_value> for $name {
-			fn get_no_op(key: &$key) -> Option<$timestamped_value> {
+		impl $crate::DataProviderExtended<$key, $account_id, $timestamped_value> for $name {
+			fn get_polkafoundry(key: &$key) -> Option<$timestamped_value> {
 				let mut values = vec![];
 				$(
-					if let Some(v) = <$provider as $crate::DataProviderExtended<$key, $timestamped_value>>::get_no_op(&key) {
+					if let Some(v) = <$provider as $crate::DataProviderExtended<$key, $account_id, $timestamped_value>>::get_polkafoundry(&key) {
+						values.push(v);
+					}
+				)*
+				$crate::data_provider::median(values)
+			}
+			fn get_concrete(key: &$key, feeder: $account_id) -> Option<$timestamped_value> {
+				let mut values = vec![];
+				$(
+					if let Some(v) = <$provider as $crate::DataProviderExtended<$key, $account_id, $timestamped_value>>::get_concrete(&key, feeder.clone()) {
 						values.push(v);
 					}
 				)*
 				$crate::data_provider::median(values)
 			}
 			fn get_all_values() -> Vec<($key, Option<$timestamped_value>)> {
-				let mut keys = sp_std::collections::btree_set::BTreeSet::new();
+				let mut values = sp_std::collections::btree_map::BTreeMap::<$key, Vec<$timestamped_value>>::new();
 				$(
-					<$provider as $crate::DataProviderExtended<$key, $timestamped_value>>::get_all_values()
+					<$provider as $crate::DataProviderExtended<$key, $account_id, $timestamped_value>>::get_all_values()
 						.into_iter()
-						.for_each(|(k, _)| { keys.insert(k); });
+						.for_each(|(k, v)| {
+							let entry = values.entry(k).or_default();
+							if let Some(v) = v {
+								entry.push(v);
+							}
+						});
 				)*
-				keys.into_iter().map(|k| (k, Self::get_no_op(&k))).collect()
+				values.into_iter().map(|(k, v)| (k, $crate::data_provider::median(v))).collect()
 			}
 		}
 	}
@@ -100,12 +117,15 @@ mod tests {
 					$price.with(|v| *v.borrow())
 				}
 			}
-			impl DataProviderExtended<u8, u8> for $provider {
-				fn get_no_op(_: &u8) -> Option<u8> {
+			impl DataProviderExtended<u8, u8, u8> for $provider {
+				fn get_polkafoundry(_: &u8) -> Option<u8> {
+					$price.with(|v| *v.borrow())
+				}
+				fn get_concrete(_: &u8, _: u8) -> Option<u8> {
 					$price.with(|v| *v.borrow())
 				}
 				fn get_all_values() -> Vec<(u8, Option<u8>)> {
-					vec![(0, Self::get_no_op(&0))]
+					vec![(0, Self::get_polkafoundry(&0))]
 				}
 			}
 		};
@@ -116,7 +136,7 @@ mod tests {
 	mock_data_provider!(Provider3, MOCK_PRICE_3);
 	mock_data_provider!(Provider4, MOCK_PRICE_4);
 
-	create_median_value_data_provider!(Providers, u8, u8, u8, [Provider1, Provider2, Provider3, Provider4]);
+	create_median_value_data_provider!(Providers, u8, u8, u8, u8, [Provider1, Provider2, Provider3, Provider4]);
 
 	#[test]
 	fn median_value_data_provider_works() {
@@ -136,6 +156,9 @@ mod tests {
 			Provider4::set_price(values[3]);
 
 			assert_eq!(<Providers as DataProvider<_, _>>::get(&0), target);
+			assert_eq!(<Providers as DataProviderExtended<_, _, _>>::get_polkafoundry(&0), target);
+			assert_eq!(<Providers as DataProviderExtended<_, _, _>>::get_concrete(&0, 1), target);
+			assert_eq!(<Providers as DataProviderExtended<_, _, _>>::get_all_values(), vec![(0, target)]);
 		}
 	}
 }